@@ -1,8 +1,8 @@
 extern crate alloc;
 
+use alloc::vec::Vec;
 use utils::api_tests_mut::RnaTraitMutLeakStorage;
 use utils::{DnaTrait, OurResult, RnaTrait, RnaTraitMut};
-use alloc::vec::Vec;
 
 pub mod wipe_on_mut;
 
@@ -21,7 +21,6 @@ pub type WithStorageLeaked<'a, RNA: RnaTraitMutLeakStorage<'a>, RES> =
     &'a dyn Fn(&RNA, WithStorageLeakedCallBack<'a, RES>) -> RES;
 
 /// A helper.
-#[allow(dead_code)] //@TODO
 fn cga_modified_to_u<'a, R: RnaTraitMut<'a>>() -> R {
     let mut rna = R::new("CGA").expect("RNA");
     rna.set_from_iter(&mut "U".chars()).expect("success");
@@ -29,7 +28,13 @@ fn cga_modified_to_u<'a, R: RnaTraitMut<'a>>() -> R {
 }
 
 /// A helper.
-#[allow(dead_code)] //@TODO
+fn cga_truncated_to_c<'a, R: RnaTraitMut<'a>>() -> R {
+    let mut rna = R::new("CGA").expect("RNA");
+    rna.truncate(1);
+    rna
+}
+
+/// A helper.
 fn leaks_g_or_a<'a, R: RnaTraitMutLeakStorage<'a>>(
     rna: &R,
     with_storage_leaked: WithStorageLeaked<'a, R, bool>,
@@ -57,8 +62,22 @@ pub trait Tests {
         Ok(())
     }
 
+    fn test_truncate() -> OurResult<()> {
+        let mut rna = Self::Rna::new("CGAU")?;
+        rna.truncate(2);
+        assert_eq!(rna, Self::Rna::new("CG")?);
+
+        rna.truncate(3);
+        assert_eq!(rna, Self::Rna::new("CG")?);
+
+        rna.truncate(0);
+        assert_eq!(rna, Self::Rna::new("")?);
+        Ok(())
+    }
+
     fn all_tests() -> OurResult<()> {
         Self::test_modify_string_based_rna()?;
+        Self::test_truncate()?;
         Ok(())
     }
 }
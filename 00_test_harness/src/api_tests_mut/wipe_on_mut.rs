@@ -2,8 +2,8 @@
 
 extern crate alloc;
 
-use utils::api_tests_mut::RnaTraitMutLeakStorage;
 use crate::api_tests_mut::{self, WithStorageLeaked};
+use utils::api_tests_mut::RnaTraitMutLeakStorage;
 use utils::DnaTrait;
 
 pub trait Tests {
@@ -19,4 +19,14 @@ pub trait Tests {
 
         assert!(!leaks);
     }
+
+    fn test_truncate_does_not_leak<'a>(
+        with_storage_leaked: WithStorageLeaked<'a, Self::Rna<'a>, bool>,
+    ) {
+        let rna = api_tests_mut::cga_truncated_to_c::<Self::Rna<'_>>();
+
+        let leaks = api_tests_mut::leaks_g_or_a(&rna, with_storage_leaked);
+
+        assert!(!leaks);
+    }
 }
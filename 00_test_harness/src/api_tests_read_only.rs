@@ -17,20 +17,23 @@ pub trait Tests {
 
     fn test_invalid_self_input() {
         // Invalid character
-        assert_eq!(Self::Dna::new("X").err(), Some(0));
+        assert_eq!(Self::Dna::new("X").err().map(usize::from), Some(0));
         // Valid nucleotide, but invalid in context
-        assert_eq!(Self::Dna::new("U").err(), Some(0));
+        assert_eq!(Self::Dna::new("U").err().map(usize::from), Some(0));
         // Longer string with contained errors
-        assert_eq!(Self::Dna::new("ACGTUXXCTTAA").err(), Some(4));
+        assert_eq!(
+            Self::Dna::new("ACGTUXXCTTAA").err().map(usize::from),
+            Some(4)
+        );
     }
 
     fn test_invalid_rna_input() {
         // Invalid character
-        assert_eq!(Self::Rna::new("X").unwrap_err(), 0);
+        assert_eq!(usize::from(Self::Rna::new("X").unwrap_err()), 0);
         // Valid nucleotide, but invalid in context
-        assert_eq!(Self::Rna::new("T").unwrap_err(), 0);
+        assert_eq!(usize::from(Self::Rna::new("T").unwrap_err()), 0);
         // Longer string with contained errors
-        assert_eq!(Self::Rna::new("ACGUTTXCUUAA").unwrap_err(), 4);
+        assert_eq!(usize::from(Self::Rna::new("ACGUTTXCUUAA").unwrap_err()), 4);
     }
 
     fn test_acid_equals_acid() {
@@ -108,6 +111,27 @@ pub trait Tests {
         Ok(())
     }
 
+    fn test_dna_nucleotide_count() -> OurResult<()> {
+        assert_eq!(Self::Dna::new("")?.nucleotide_count(), 0);
+        assert_eq!(Self::Dna::new("GCTA")?.nucleotide_count(), 4);
+        assert_eq!(Self::Dna::new("ACGTGGTCTTAA")?.nucleotide_count(), 12);
+        Ok(())
+    }
+
+    fn test_dna_is_empty() -> OurResult<()> {
+        assert!(Self::Dna::new("")?.is_empty());
+        assert!(!Self::Dna::new("GCTA")?.is_empty());
+        Ok(())
+    }
+
+    fn test_dna_rna_len() -> OurResult<()> {
+        assert_eq!(Self::Dna::new("")?.rna_len(), 0);
+        let dna = Self::Dna::new("GCTA")?;
+        assert_eq!(dna.rna_len(), 4);
+        assert_eq!(dna.rna_len(), dna.nucleotide_count());
+        Ok(())
+    }
+
     fn all_tests() {
         Self::test_valid_self_input();
         Self::test_valid_rna_input();
@@ -121,5 +145,8 @@ pub trait Tests {
         Self::test_transcribes_all_self_to_rna();
         assert!(Self::test_rna_given_nucleotides_debug().is_ok());
         assert!(Self::test_rna_from_dna_debug().is_ok());
+        assert!(Self::test_dna_nucleotide_count().is_ok());
+        assert!(Self::test_dna_is_empty().is_ok());
+        assert!(Self::test_dna_rna_len().is_ok());
     }
 }
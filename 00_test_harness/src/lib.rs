@@ -1,5 +1,2 @@
-#![feature(generic_associated_types)]
-#![feature(associated_type_defaults)]
-
 pub mod api_tests_mut;
-pub mod api_tests_read_only;
\ No newline at end of file
+pub mod api_tests_read_only;
@@ -1,49 +1,125 @@
+use crate::{InvalidNucleotide, NucleotideKind};
+
+#[cfg(not(feature = "iupac"))]
 const DNA_NUCLEOTIDES: &str = "GCTA";
+#[cfg(not(feature = "iupac"))]
 const RNA_NUCLEOTIDES: &str = "CGAU";
+/// With IUPAC ambiguity code N (any nucleotide).
+#[cfg(feature = "iupac")]
+const DNA_NUCLEOTIDES: &str = "GCTAN";
+#[cfg(feature = "iupac")]
+const RNA_NUCLEOTIDES: &str = "CGAUN";
 
 /// Result of [`check`] and related functions.
 ///
 /// It doesn't need to be public (even if used in signatures of public functions here - because
 /// those get exposed with the type resolved, without the alias).
-type CheckResult = crate::OurResult<()>;
+type CheckResult = Result<(), InvalidNucleotide>;
 
 /// Check that any characters from `chars_to_be_checked` are in `allowed_chars`. See [`check_dna`]
 /// or [`check_rna_str`] for result type description.
-fn check(to_be_checked: impl Iterator<Item = char>, allowed: &str) -> CheckResult {
-    for (i, c) in to_be_checked.enumerate() {
-        if !allowed.contains(c) {
-            return Err(i);
+fn check(
+    to_be_checked: impl Iterator<Item = char>,
+    allowed: &str,
+    kind: NucleotideKind,
+) -> CheckResult {
+    for (index, found) in to_be_checked.enumerate() {
+        if !allowed.contains(found) {
+            return Err(InvalidNucleotide { index, found, kind });
         }
     }
     Ok(())
 }
 
 /// Check that `dna` contains DNA nucleotides only. On success return [`Ok(())`](Ok). On error
-/// return [`Err`] with a 0-based index of the first incorrect character.
+/// return [`Err`] with the first incorrect character and its 0-based index.
 pub fn check_dna(dna: &str) -> CheckResult {
-    check(dna.chars(), DNA_NUCLEOTIDES)
+    check(dna.chars(), DNA_NUCLEOTIDES, NucleotideKind::Dna)
+}
+
+/// Like [`check_dna`] (with the same result), but validating 32 bytes at a time with SIMD. The
+/// exact error index is determined by [`check_dna`] (scalar), starting from the first invalid
+/// chunk. The remaining tail (shorter than a chunk) is checked by [`check_dna`], too. (With
+/// feature `iupac`, any chunk containing N is left to [`check_dna`], too.)
+#[cfg(feature = "portable_simd")]
+pub fn check_dna_fast(dna: &str) -> CheckResult {
+    use core::simd::{cmp::SimdPartialEq, u8x32};
+    const LANES: usize = 32;
+
+    let [a, c, g, t] = b"ACGT".map(u8x32::splat);
+    let (chunks, _tail) = dna.as_bytes().as_chunks::<LANES>();
+    // Any chunk before the first invalid chunk consists of ASCII nucleotides only. So `offset`
+    // is both a byte index (a char boundary) and a char index.
+    let offset = chunks
+        .iter()
+        .position(|&chunk| {
+            let bytes = u8x32::from_array(chunk);
+            let valid = bytes.simd_eq(a) | bytes.simd_eq(c) | bytes.simd_eq(g) | bytes.simd_eq(t);
+            !valid.all()
+        })
+        .unwrap_or(chunks.len())
+        * LANES;
+    check_dna(&dna[offset..]).map_err(|e| InvalidNucleotide {
+        index: offset + e.index,
+        ..e
+    })
 }
 
 /// Check that `rna_iter` yields RNA nucleotides only. On success return [`Ok(())`](Ok). On error
-/// return [`Err`] with a 0-based index of the first incorrect character.
+/// return [`Err`] with the first incorrect character and its 0-based index.
 pub fn check_rna_char_iter(rna_iter: impl Iterator<Item = char>) -> CheckResult {
-    check(rna_iter, RNA_NUCLEOTIDES)
+    check(rna_iter, RNA_NUCLEOTIDES, NucleotideKind::Rna)
 }
 
 /// Check that `rna` contains RNA nucleotides only. On success return [`Ok(())`](Ok). On error
-/// return [`Err`] with a 0-based index of the first incorrect character.
+/// return [`Err`] with the first incorrect character and its 0-based index.
+///
+/// This checks bytes (rather than decoding chars). That is Unicode-safe, too: any bytes before
+/// the first invalid byte are ASCII nucleotides, so its byte index is also its char index, even if
+/// it starts a multi-byte character.
 pub fn check_rna_str(rna: &str) -> CheckResult {
-    check_rna_char_iter(rna.chars())
+    match rna
+        .bytes()
+        .position(|b| !RNA_NUCLEOTIDES.as_bytes().contains(&b))
+    {
+        Some(index) => Err(InvalidNucleotide {
+            index,
+            found: rna[index..]
+                .chars()
+                .next()
+                .expect("a char at a char boundary"),
+            kind: NucleotideKind::Rna,
+        }),
+        None => Ok(()),
+    }
 }
 
 /// Check that `rna` contains RNA nucleotides only. On success return [`Ok(())`](Ok). On error
-/// return [`Err`] with a 0-based index of the first incorrect character.
+/// return [`Err`] with the first incorrect character and its 0-based index.
 pub fn check_rna_chars(rna: &[char]) -> CheckResult {
     check_rna_char_iter(rna.iter().cloned())
 }
 
 #[cfg(test)]
 mod tests {
+    use super::{InvalidNucleotide, NucleotideKind};
+
+    fn dna_invalid(index: usize, found: char) -> InvalidNucleotide {
+        InvalidNucleotide {
+            index,
+            found,
+            kind: NucleotideKind::Dna,
+        }
+    }
+
+    fn rna_invalid(index: usize, found: char) -> InvalidNucleotide {
+        InvalidNucleotide {
+            index,
+            found,
+            kind: NucleotideKind::Rna,
+        }
+    }
+
     #[test]
     fn test_check_dna_rna_valid() {
         assert!(super::check_dna("GCTA").is_ok());
@@ -52,33 +128,110 @@ mod tests {
 
     #[test]
     fn test_check_dna_rna_invalid() {
-        assert_eq!(super::check_dna("CU"), Err(1));
-        assert_eq!(super::check_rna_str("CT"), Err(1));
+        assert_eq!(super::check_dna("CU"), Err(dna_invalid(1, 'U')));
+        assert_eq!(super::check_rna_str("CT"), Err(rna_invalid(1, 'T')));
     }
 
     #[test]
     fn test_check_dna() {
         assert!(super::check_dna("GCTA").is_ok());
-        assert_eq!(super::check_dna("U"), Err(0));
-        assert_eq!(super::check_dna("GX"), Err(1));
+        assert_eq!(super::check_dna("U"), Err(dna_invalid(0, 'U')));
+        assert_eq!(super::check_dna("GX"), Err(dna_invalid(1, 'X')));
+    }
+
+    #[cfg(feature = "iupac")]
+    #[test]
+    fn test_check_iupac_n() {
+        assert!(super::check_dna("ACGTN").is_ok());
+        assert!(super::check_rna_str("ACGUN").is_ok());
+    }
+
+    #[cfg(not(feature = "iupac"))]
+    #[test]
+    fn test_check_no_iupac_n() {
+        assert_eq!(super::check_dna("ACGTN"), Err(dna_invalid(4, 'N')));
+        assert_eq!(super::check_rna_str("ACGUN"), Err(rna_invalid(4, 'N')));
+    }
+
+    #[test]
+    fn test_check_dna_found() {
+        let error = super::check_dna("ACGTXACGT").unwrap_err();
+        assert_eq!(error.found, 'X');
+        assert_eq!(error.index, 4);
+        assert_eq!(error.kind, NucleotideKind::Dna);
+        assert_eq!(usize::from(error), 4);
+        assert_eq!(
+            crate::NucleotideError::from(error),
+            crate::NucleotideError::Invalid(4)
+        );
+
+        let error = super::check_rna_str("CGAé").unwrap_err();
+        assert_eq!((error.index, error.found), (3, 'é'));
+        assert_eq!(error.kind, NucleotideKind::Rna);
+    }
+
+    #[cfg(feature = "portable_simd")]
+    #[test]
+    fn test_check_dna_fast() {
+        assert!(super::check_dna_fast("GCTA").is_ok());
+        assert_eq!(super::check_dna_fast("GX"), Err(dna_invalid(1, 'X')));
+
+        // Pseudo-random (linear congruential generator) inputs, some with non-ASCII characters.
+        let mut seed = 12345u32;
+        let mut next = || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (seed >> 16) as usize
+        };
+        for _ in 0..1000 {
+            let len = next() % 100;
+            let invalid_probability = next() % 200;
+            let dna = (0..len)
+                .map(|_| match next() % 1000 {
+                    n if n < invalid_probability / 2 => 'X',
+                    n if n < invalid_probability => 'é',
+                    n => ['A', 'C', 'G', 'T'][n % 4],
+                })
+                .collect::<String>();
+            assert_eq!(super::check_dna_fast(&dna), super::check_dna(&dna), "{dna}");
+        }
     }
 
     #[test]
     fn test_check_rna_iter() {
         assert!(super::check_rna_char_iter("CGAU".chars()).is_ok());
-        assert_eq!(super::check_rna_char_iter("T".chars()), Err(0));
-        assert_eq!(super::check_rna_char_iter("GX".chars()), Err(1));
+        assert_eq!(
+            super::check_rna_char_iter("T".chars()),
+            Err(rna_invalid(0, 'T'))
+        );
+        assert_eq!(
+            super::check_rna_char_iter("GX".chars()),
+            Err(rna_invalid(1, 'X'))
+        );
     }
     #[test]
     fn test_check_rna_str() {
         assert!(super::check_rna_str("CGAU").is_ok());
-        assert_eq!(super::check_rna_str("T"), Err(0));
-        assert_eq!(super::check_rna_str("GX"), Err(1));
+        assert_eq!(super::check_rna_str("T"), Err(rna_invalid(0, 'T')));
+        assert_eq!(super::check_rna_str("GX"), Err(rna_invalid(1, 'X')));
+    }
+    #[test]
+    fn test_check_rna_str_matches_char_index() {
+        for rna in ["CGAU", "CGAX", "UT", "GéX", "CGé", "é", "ÉA", ""] {
+            assert_eq!(
+                super::check_rna_str(rna),
+                super::check_rna_char_iter(rna.chars()),
+                "{rna}"
+            );
+        }
+        assert_eq!(super::check_rna_str("GCé"), Err(rna_invalid(2, 'é')));
     }
     #[test]
     fn test_check_rna_chars() {
         assert!(super::check_rna_chars(&['C', 'G', 'A', 'U']).is_ok());
-        assert_eq!(super::check_rna_chars(&['T']), Err(0));
-        assert_eq!(super::check_rna_chars(&['G', 'X']), Err(1));
+        assert_eq!(super::check_rna_chars(&['T']), Err(rna_invalid(0, 'T')));
+        assert_eq!(
+            super::check_rna_chars(&['G', 'X']),
+            Err(rna_invalid(1, 'X'))
+        );
     }
 }
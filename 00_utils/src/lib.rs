@@ -1,4 +1,5 @@
-use core::fmt::Debug;
+#![cfg_attr(feature = "portable_simd", feature(portable_simd))]
+use core::fmt::{self, Debug, Display, Formatter};
 
 pub mod api_tests_mut;
 pub mod checks;
@@ -8,6 +9,27 @@ where
     Rna: RnaTrait<'a> + 'a,
 {
     fn new(dna: &'a str) -> OurResult<Self>;
+
+    /// The DNA nucleotides, as given to [`DnaTrait::new`].
+    fn as_str(&self) -> &str;
+
+    /// Number of DNA nucleotides.
+    fn nucleotide_count(&self) -> usize {
+        self.as_str().chars().count()
+    }
+
+    /// Whether there are no DNA nucleotides.
+    fn is_empty(&self) -> bool {
+        self.as_str().is_empty()
+    }
+
+    /// Number of RNA nucleotides that [`DnaTrait::into_rna`] produces (for sizing buffers before
+    /// transcribing). Transcription is 1:1, so this is the same as
+    /// [`DnaTrait::nucleotide_count`].
+    fn rna_len(&self) -> usize {
+        self.nucleotide_count()
+    }
+
     /// The common practice is that `into_*` methods consume `self` (rather than taking it by
     /// reference). However, it's OK to take by reference where possible - making the API flexible.
     #[allow(clippy::wrong_self_convention)]
@@ -22,26 +44,379 @@ pub trait RnaTraitMut<'a>: RnaTrait<'a> {
     /// Mutate `self`: Make it store all characters in the given `iter`. Fail if `iter` doesn't
     /// satisfy requirements particular of the given implementation.
     fn set_from_iter(&mut self, iter: &mut dyn Iterator<Item = char>) -> OurResult<()>;
+
+    /// Mutate `self`: Keep only the first `new_len` nucleotides. If `new_len` is not less than the
+    /// current length, this has no effect. Implementations must securely wipe any storage that is
+    /// no longer used.
+    fn truncate(&mut self, new_len: usize);
+}
+
+/// Parse whitespace-separated (for example, space- or newline-separated) RNA sequences in `input`.
+/// Yield each one validated by [`RnaTrait::new`], so an invalid token yields [`Err`] (with a
+/// 0-based index within that token), and parsing continues with the next token.
+pub fn parse_many<'a, Rna: RnaTrait<'a> + 'a>(
+    input: &'a str,
+) -> impl Iterator<Item = OurResult<Rna>> + 'a {
+    input.split_whitespace().map(Rna::new)
 }
 
-/// Custom result type. It works with our Exercism exercise (the error variant uses `usize` to
-/// indicate a 0-based character index that is not a valid DNA/RNA nucleotide). Type parameter `T`
-/// is the success variant type, carrying a result as needed.
+/// Custom result type. The error variant is a [`NucleotideError`]. For our Exercism exercise (which
+/// uses `usize` to indicate a 0-based character index that is not a valid DNA/RNA nucleotide),
+/// convert it with [`usize::from`]. Type parameter `T` is the success variant type, carrying a
+/// result as needed.
 ///
 // New to Rust? Question mark operator shortcuts on error and returns it here.
-pub type OurResult<T> = Result<T, usize>;
+pub type OurResult<T> = Result<T, NucleotideError>;
+
+/// A structured error about nucleotides. Each variant carries a 0-based index. Get it with
+/// [`NucleotideError::to_index`], or [`usize::from`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NucleotideError {
+    /// Index of the first character that is not a valid nucleotide.
+    Invalid(usize),
+    /// Index of the first nucleotide that doesn't fit in the storage.
+    Overflow(usize),
+    /// An index (or a length) that is out of range.
+    OutOfRange(usize),
+}
+
+impl NucleotideError {
+    /// The 0-based index carried by any variant.
+    pub fn to_index(&self) -> usize {
+        match *self {
+            Self::Invalid(index) | Self::Overflow(index) | Self::OutOfRange(index) => index,
+        }
+    }
+}
+
+/// For backwards compatibility with `usize`-based errors (and the `?` operator in functions
+/// returning them).
+impl From<NucleotideError> for usize {
+    fn from(error: NucleotideError) -> usize {
+        error.to_index()
+    }
+}
+
+impl Display for NucleotideError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(index) => write!(f, "invalid nucleotide at index {index}"),
+            Self::Overflow(index) => write!(f, "storage overflow at index {index}"),
+            Self::OutOfRange(index) => write!(f, "index {index} out of range"),
+        }
+    }
+}
+
+/// Which nucleotides were expected. See [`InvalidNucleotide`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NucleotideKind {
+    Dna,
+    Rna,
+}
+
+/// A character that is not a valid nucleotide, as reported by [`checks`] functions. Convert it
+/// to a [`NucleotideError::Invalid`] (which keeps only the index) with `?` or [`From`], or to a
+/// `usize` (the index) with [`usize::from`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct InvalidNucleotide {
+    /// 0-based character index.
+    pub index: usize,
+    /// The offending character.
+    pub found: char,
+    /// Whether a DNA or RNA nucleotide was expected.
+    pub kind: NucleotideKind,
+}
+
+impl From<InvalidNucleotide> for NucleotideError {
+    fn from(error: InvalidNucleotide) -> Self {
+        Self::Invalid(error.index)
+    }
+}
+
+/// For migrating `usize`-based errors incrementally.
+impl From<InvalidNucleotide> for usize {
+    fn from(error: InvalidNucleotide) -> usize {
+        error.index
+    }
+}
+
+impl Display for InvalidNucleotide {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid {:?} nucleotide {:?} at index {}",
+            self.kind, self.found, self.index
+        )
+    }
+}
+
+/// RNA nucleotide, typed. Unlike a `char`, it can be matched exhaustively.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Nucleotide {
+    A,
+    C,
+    G,
+    U,
+    /// Any nucleotide (IUPAC ambiguity code).
+    #[cfg(feature = "iupac")]
+    N,
+}
+
+impl TryFrom<char> for Nucleotide {
+    /// The given character, if it's not an RNA nucleotide.
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            'A' => Ok(Nucleotide::A),
+            'C' => Ok(Nucleotide::C),
+            'G' => Ok(Nucleotide::G),
+            'U' => Ok(Nucleotide::U),
+            #[cfg(feature = "iupac")]
+            'N' => Ok(Nucleotide::N),
+            _ => Err(c),
+        }
+    }
+}
+
+impl From<Nucleotide> for char {
+    fn from(nucleotide: Nucleotide) -> char {
+        match nucleotide {
+            Nucleotide::A => 'A',
+            Nucleotide::C => 'C',
+            Nucleotide::G => 'G',
+            Nucleotide::U => 'U',
+            #[cfg(feature = "iupac")]
+            Nucleotide::N => 'N',
+        }
+    }
+}
+
+/// Number of each RNA nucleotide. See [`validate_and_count`].
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct NucleotideCounts {
+    pub a: usize,
+    pub c: usize,
+    pub g: usize,
+    pub u: usize,
+    #[cfg(feature = "iupac")]
+    pub n: usize,
+}
+
+/// Validate RNA nucleotides in `rna` and count them, in one pass. On success return the counts.
+/// On error return [`Err`] with a 0-based index of the first incorrect character.
+pub fn validate_and_count(rna: &str) -> OurResult<NucleotideCounts> {
+    let mut counts = NucleotideCounts::default();
+    for (i, c) in rna.chars().enumerate() {
+        let count = match Nucleotide::try_from(c).map_err(|_| NucleotideError::Invalid(i))? {
+            Nucleotide::A => &mut counts.a,
+            Nucleotide::C => &mut counts.c,
+            Nucleotide::G => &mut counts.g,
+            Nucleotide::U => &mut counts.u,
+            #[cfg(feature = "iupac")]
+            Nucleotide::N => &mut counts.n,
+        };
+        *count += 1;
+    }
+    Ok(counts)
+}
+
+/// 2-bit code of nucleotide `c` (A = 0, C = 1, G = 2, and T or U = 3), for packed encodings. On
+/// error (for example, for an ambiguity code like `N`) return [`Err`] with `c`. See
+/// [`two_bit_to_base`].
+pub fn base_to_2bit(c: char) -> Result<u8, char> {
+    match c {
+        'A' => Ok(0),
+        'C' => Ok(1),
+        'G' => Ok(2),
+        'T' | 'U' => Ok(3),
+        _ => Err(c),
+    }
+}
+
+/// RNA nucleotide with 2-bit code `v` (the inverse of [`base_to_2bit`], with U for 3). [`panic`] if
+/// `v` is more than 3.
+pub fn two_bit_to_base(v: u8) -> char {
+    match v {
+        0 => 'A',
+        1 => 'C',
+        2 => 'G',
+        3 => 'U',
+        _ => panic!("Invalid 2-bit code {v}."),
+    }
+}
 
 /// Translate DNA nucleotide `dna_nucl` to a RNA nucleaotide. [`panic`] if `dna_nucl` is invalid.
+/// With feature `iupac`, N (any nucleotide) stays N.
 pub fn dna_to_rna(dna_nucl: char) -> char {
     match dna_nucl {
         'G' => 'C',
         'C' => 'G',
         'T' => 'A',
         'A' => 'U',
+        #[cfg(feature = "iupac")]
+        'N' => 'N',
         _ => panic!("Unrecognized nucleotide {dna_nucl}."),
     }
 }
 
+/// Like [`dna_to_rna`], but on error (if `dna_nucl` is not a DNA nucleotide) return [`Err`] with
+/// [`InvalidNucleotide`] carrying `dna_nucl`, rather than panicking. The error's `index` is always
+/// `0`, since this only sees one character: a caller that knows the position must set it before
+/// propagating. `?` converts it to [`NucleotideError::Invalid`].
+pub fn transcribe_char_checked(dna_nucl: char) -> Result<char, InvalidNucleotide> {
+    match dna_nucl {
+        'G' | 'C' | 'T' | 'A' => Ok(dna_to_rna(dna_nucl)),
+        #[cfg(feature = "iupac")]
+        'N' => Ok(dna_to_rna(dna_nucl)),
+        _ => Err(InvalidNucleotide {
+            index: 0,
+            found: dna_nucl,
+            kind: NucleotideKind::Dna,
+        }),
+    }
+}
+
+/// Translate RNA nucleotide `rna_nucl` back to a DNA nucleotide (the inverse of [`dna_to_rna`]).
+/// [`panic`] if `rna_nucl` is invalid (for example, `'T'`, or `'N'` without feature `iupac`).
+pub fn rna_to_dna(rna_nucl: char) -> char {
+    let dna_nucl = match rna_nucl {
+        'C' => 'G',
+        'G' => 'C',
+        'A' => 'T',
+        'U' => 'A',
+        #[cfg(feature = "iupac")]
+        'N' => 'N',
+        _ => panic!("Unrecognized nucleotide {rna_nucl}."),
+    };
+    debug_assert_eq!(dna_to_rna(dna_nucl), rna_nucl);
+    dna_nucl
+}
+
+/// Transcribe DNA nucleotides (ASCII bytes) in `dna` to RNA nucleotides (ASCII bytes), written to
+/// `out`. On success return the number of bytes written. On error return [`Err`] with a 0-based
+/// index of the first incorrect DNA byte, or with `out.len()` if `out` is too short (that's the
+/// index of the first byte that doesn't fit). On error, `out` may have been partially written to.
+pub fn transcribe_slice(dna: &[u8], out: &mut [u8]) -> Result<usize, usize> {
+    let out_len = out.len();
+    for (i, &dna_nucl) in dna.iter().enumerate() {
+        let rna_nucl = out.get_mut(i).ok_or(out_len)?;
+        *rna_nucl = match dna_nucl {
+            b'G' => b'C',
+            b'C' => b'G',
+            b'T' => b'A',
+            b'A' => b'U',
+            #[cfg(feature = "iupac")]
+            b'N' => b'N',
+            _ => return Err(i),
+        };
+    }
+    Ok(dna.len())
+}
+
+/// Build a 256 entry lookup table mapping each byte in `pairs` to its pair, both ways. All other
+/// entries are zero (that is, invalid).
+const fn complement_table(pairs: &[(u8, u8)]) -> [u8; 256] {
+    let mut table = [0u8; 256];
+    let mut i = 0;
+    while i < pairs.len() {
+        let (a, b) = pairs[i];
+        table[a as usize] = b;
+        table[b as usize] = a;
+        i += 1;
+    }
+    table
+}
+
+/// Complement of each DNA nucleotide (ASCII byte), or zero if the byte is not a DNA nucleotide.
+#[cfg(not(feature = "iupac"))]
+const DNA_COMPLEMENT: [u8; 256] = complement_table(&[(b'A', b'T'), (b'C', b'G')]);
+#[cfg(feature = "iupac")]
+const DNA_COMPLEMENT: [u8; 256] = complement_table(&[(b'A', b'T'), (b'C', b'G'), (b'N', b'N')]);
+/// Complement of each RNA nucleotide (ASCII byte), or zero if the byte is not an RNA nucleotide.
+#[cfg(not(feature = "iupac"))]
+const RNA_COMPLEMENT: [u8; 256] = complement_table(&[(b'A', b'U'), (b'C', b'G')]);
+#[cfg(feature = "iupac")]
+const RNA_COMPLEMENT: [u8; 256] = complement_table(&[(b'A', b'U'), (b'C', b'G'), (b'N', b'N')]);
+
+/// Complement of DNA nucleotide `dna_nucl` (ASCII byte). Return [`None`] if it's invalid. With
+/// feature `iupac`, N (any nucleotide) complements to N.
+pub fn dna_complement(dna_nucl: u8) -> Option<u8> {
+    match DNA_COMPLEMENT[dna_nucl as usize] {
+        0 => None,
+        complement => Some(complement),
+    }
+}
+
+/// Complement of RNA nucleotide `rna_nucl` (ASCII byte). Return [`None`] if it's invalid. With
+/// feature `iupac`, N (any nucleotide) complements to N.
+pub fn rna_complement(rna_nucl: u8) -> Option<u8> {
+    match RNA_COMPLEMENT[rna_nucl as usize] {
+        0 => None,
+        complement => Some(complement),
+    }
+}
+
+/// Write the reverse complement of DNA nucleotides (ASCII bytes) in `dna` to `out`. On success
+/// return the number of bytes written. On error return [`Err`] with `out.len()` if `out` is too
+/// short (checked first, before writing anything), or with a 0-based index (within `dna`) of the
+/// last incorrect DNA byte (the first one reached, since `dna` is processed back-to-front). On
+/// error, `out` may have been partially written to.
+pub fn reverse_complement_into(dna: &[u8], out: &mut [u8]) -> Result<usize, usize> {
+    if dna.len() > out.len() {
+        return Err(out.len());
+    }
+    for (i, (o, &dna_nucl)) in out.iter_mut().zip(dna.iter().rev()).enumerate() {
+        *o = dna_complement(dna_nucl).ok_or(dna.len() - 1 - i)?;
+    }
+    Ok(dna.len())
+}
+
+/// Standard genetic code. Amino acids (as single letters), or `*` for stop codons. Indexed by
+/// `16 * first + 4 * second + third`, where each base is numbered in order U, C, A, G.
+const GENETIC_CODE: &[u8; 64] = b"FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG";
+
+/// Index of RNA `codon` (between `0` and `63`): `16 * first + 4 * second + third`, where each
+/// base is numbered in order U, C, A, G (the order of the standard genetic code table). [`panic`]
+/// if `codon` contains a non-RNA nucleotide.
+pub fn codon_index(codon: [char; 3]) -> usize {
+    codon.iter().fold(0, |index, &base| {
+        4 * index
+            + match base {
+                'U' => 0,
+                'C' => 1,
+                'A' => 2,
+                'G' => 3,
+                _ => panic!("Unrecognized nucleotide {base}."),
+            }
+    })
+}
+
+/// Whether RNA `codon` is the start codon (AUG).
+pub fn is_start_codon(codon: [char; 3]) -> bool {
+    codon == ['A', 'U', 'G']
+}
+
+/// Whether RNA `codon` is a stop codon (UAA, UAG or UGA).
+pub fn is_stop_codon(codon: [char; 3]) -> bool {
+    matches!(codon, ['U', 'A', 'A'] | ['U', 'A', 'G'] | ['U', 'G', 'A'])
+}
+
+/// Translate RNA `codon` to its amino acid (as a single letter, for example `'M'` for
+/// Methionine). Return [`None`] for stop codons. [`panic`] if `codon` contains a non-RNA
+/// nucleotide. With feature `iupac`, a codon containing N (any nucleotide) translates to `'X'`
+/// (any amino acid), even if it could be a stop codon.
+pub fn translate_codon(codon: [char; 3]) -> Option<char> {
+    #[cfg(feature = "iupac")]
+    if codon.contains(&'N') {
+        return Some('X');
+    }
+    match GENETIC_CODE[codon_index(codon)] {
+        b'*' => None,
+        amino_acid => Some(amino_acid as char),
+    }
+}
+
 /// Iterate over `rna_iter` until its end. Transform its characters to UTF-8 and store them in
 /// `result`. Return number (length) of copied UTF-8 bytes. Panic if `result` doesn't have enough
 /// space.
@@ -64,6 +439,57 @@ pub fn char_iter_to_bytes(result: &mut [u8], char_iter: impl Iterator<Item = cha
     result_idx
 }
 
+/// Hamming distance between `a` and `b`: the number of positions where their characters differ.
+/// Return [`None`] if they have different lengths. Both can be any char sources, for example,
+/// [`str::chars`], or a lazy transcription iterator.
+pub fn hamming(
+    mut a: impl Iterator<Item = char>,
+    mut b: impl Iterator<Item = char>,
+) -> Option<usize> {
+    let mut differences = 0;
+    loop {
+        match (a.next(), b.next()) {
+            (Some(a_char), Some(b_char)) => differences += usize::from(a_char != b_char),
+            (None, None) => return Some(differences),
+            _ => return None,
+        }
+    }
+}
+
+/// Levenshtein (edit) distance between `a` and `b`: the minimum number of single character
+/// insertions, deletions or substitutions that turn `a` into `b`. Unlike Hamming distance, `a` and
+/// `b` may have different lengths.
+///
+/// Heapless: `scratch` is a working row provided by the caller. It must have at least
+/// `b.chars().count() + 1` items (any extra items are left untouched). Any previous content of
+/// those items is overwritten. Panic if `scratch` doesn't have enough space.
+pub fn levenshtein(a: &str, b: &str, scratch: &mut [usize]) -> usize {
+    let b_len = b.chars().count();
+    assert!(
+        scratch.len() > b_len,
+        "levenshtein() needs scratch with at least {} items, but it has {}.",
+        b_len + 1,
+        scratch.len()
+    );
+    // Only one row of the (a.len() + 1) x (b.len() + 1) matrix is stored at any time.
+    let row = &mut scratch[..=b_len];
+    for (j, item) in row.iter_mut().enumerate() {
+        *item = j;
+    }
+    for (i, a_char) in a.chars().enumerate() {
+        // Value of row[j] from the previous row, before it was overwritten.
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b_char) in b.chars().enumerate() {
+            let above = row[j + 1];
+            let substitution = diagonal + usize::from(a_char != b_char);
+            row[j + 1] = substitution.min(above + 1).min(row[j] + 1);
+            diagonal = above;
+        }
+    }
+    row[b_len]
+}
+
 // Not public - not a part of public API.
 struct CharBytesIter {
     char_bytes: [u8; 4],
@@ -98,7 +524,7 @@ impl CharBytesIter {
 }
 
 pub fn char_iter_to_byte_iter(char_iter: impl Iterator<Item = char>) -> impl Iterator<Item = u8> {
-    char_iter.flat_map(|c| CharBytesIter::new(c))
+    char_iter.flat_map(CharBytesIter::new)
 }
 
 #[cfg(test)]
@@ -117,5 +543,358 @@ mod tests {
         super::dna_to_rna('U');
     }
 
+    #[test]
+    fn test_transcribe_char_checked() {
+        use super::{transcribe_char_checked, InvalidNucleotide, NucleotideError, NucleotideKind};
+        for c in ['G', 'C', 'T', 'A'] {
+            assert_eq!(transcribe_char_checked(c), Ok(super::dna_to_rna(c)));
+        }
+        for c in ['U', 'X', 'a', 'é'] {
+            assert_eq!(
+                transcribe_char_checked(c),
+                Err(InvalidNucleotide {
+                    index: 0,
+                    found: c,
+                    kind: NucleotideKind::Dna
+                })
+            );
+        }
+
+        fn transcribe_all(dna: &str) -> super::OurResult<()> {
+            for (i, c) in dna.chars().enumerate() {
+                transcribe_char_checked(c).map_err(|e| InvalidNucleotide { index: i, ..e })?;
+            }
+            Ok(())
+        }
+        assert_eq!(transcribe_all("GCTA"), Ok(()));
+        assert_eq!(transcribe_all("GCUA"), Err(NucleotideError::Invalid(2)));
+    }
+
+    #[test]
+    fn test_rna_to_dna() {
+        assert_eq!(super::rna_to_dna('U'), 'A');
+        assert_eq!(super::rna_to_dna('A'), 'T');
+        assert_eq!(super::rna_to_dna('G'), 'C');
+        assert_eq!(super::rna_to_dna('C'), 'G');
+    }
+
+    #[test]
+    fn test_rna_to_dna_round_trip() {
+        use super::{dna_to_rna, rna_to_dna};
+        for c in ['A', 'C', 'G', 'T'] {
+            assert_eq!(rna_to_dna(dna_to_rna(c)), c);
+        }
+    }
+
+    #[cfg(feature = "iupac")]
+    #[test]
+    fn test_iupac_n_transcription() {
+        assert_eq!(super::dna_to_rna('N'), 'N');
+        assert_eq!(super::rna_to_dna('N'), 'N');
+        assert_eq!(super::transcribe_char_checked('N'), Ok('N'));
+        let mut out = [0u8; 3];
+        assert_eq!(super::transcribe_slice(b"ANT", &mut out), Ok(3));
+        assert_eq!(&out, b"UNA");
+    }
+
+    #[cfg(feature = "iupac")]
+    #[test]
+    fn test_iupac_n_nucleotide() {
+        use super::Nucleotide;
+        assert_eq!(Nucleotide::try_from('N'), Ok(Nucleotide::N));
+        assert_eq!(char::from(Nucleotide::N), 'N');
+        assert_eq!(
+            super::validate_and_count("ANNU").map(|counts| counts.n),
+            Ok(2)
+        );
+        assert_eq!(super::dna_complement(b'N'), Some(b'N'));
+        assert_eq!(super::rna_complement(b'N'), Some(b'N'));
+        let mut out = [0u8; 3];
+        assert_eq!(super::reverse_complement_into(b"ANC", &mut out), Ok(3));
+        assert_eq!(&out, b"GNT");
+        assert_eq!(super::translate_codon(['A', 'U', 'N']), Some('X'));
+        assert_eq!(super::translate_codon(['U', 'A', 'N']), Some('X'));
+    }
+
+    #[cfg(not(feature = "iupac"))]
+    #[test]
+    #[should_panic]
+    fn test_rna_to_dna_panic_invalid() {
+        super::rna_to_dna('N');
+    }
+
     // @TODO test for char_iter_to_bytes()
+
+    #[test]
+    fn test_complement_tables() {
+        use super::{dna_complement, rna_complement};
+        for b in 0..=u8::MAX {
+            let dna_expected = match b {
+                b'A' => Some(b'T'),
+                b'T' => Some(b'A'),
+                b'C' => Some(b'G'),
+                b'G' => Some(b'C'),
+                #[cfg(feature = "iupac")]
+                b'N' => Some(b'N'),
+                _ => None,
+            };
+            assert_eq!(dna_complement(b), dna_expected);
+            let rna_expected = match b {
+                b'A' => Some(b'U'),
+                b'U' => Some(b'A'),
+                b'C' => Some(b'G'),
+                b'G' => Some(b'C'),
+                #[cfg(feature = "iupac")]
+                b'N' => Some(b'N'),
+                _ => None,
+            };
+            assert_eq!(rna_complement(b), rna_expected);
+        }
+        for b in *b"ACGT" {
+            assert_eq!(dna_complement(b).and_then(dna_complement), Some(b));
+        }
+        for b in *b"ACGU" {
+            assert_eq!(rna_complement(b).and_then(rna_complement), Some(b));
+        }
+    }
+
+    /// Transcription is the DNA complement, with T replaced by U.
+    #[test]
+    fn test_dna_to_rna_consistent_with_complement() {
+        use super::{dna_complement, dna_to_rna};
+        for b in *b"ACGT" {
+            let complement = match dna_complement(b).unwrap() {
+                b'T' => 'U',
+                other => other as char,
+            };
+            assert_eq!(dna_to_rna(b as char), complement);
+        }
+    }
+
+    #[test]
+    fn test_validate_and_count() {
+        use super::{validate_and_count, NucleotideCounts};
+        let counts = validate_and_count("AUGGCCAU").unwrap();
+        assert_eq!((counts.a, counts.c, counts.g, counts.u), (2, 2, 2, 2));
+        assert_eq!(validate_and_count(""), Ok(NucleotideCounts::default()));
+        assert_eq!(
+            validate_and_count("AUGT"),
+            Err(super::NucleotideError::Invalid(3))
+        );
+    }
+
+    #[test]
+    fn test_two_bit_round_trip() {
+        use super::{base_to_2bit, two_bit_to_base};
+        for c in ['A', 'C', 'G', 'U'] {
+            assert_eq!(base_to_2bit(c).map(two_bit_to_base), Ok(c));
+        }
+        assert_eq!(base_to_2bit('T'), base_to_2bit('U'));
+        for v in 0..4 {
+            assert_eq!(base_to_2bit(two_bit_to_base(v)), Ok(v));
+        }
+    }
+
+    #[test]
+    fn test_base_to_2bit_invalid() {
+        assert_eq!(super::base_to_2bit('N'), Err('N'));
+        assert_eq!(super::base_to_2bit('a'), Err('a'));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_two_bit_to_base_panic_invalid() {
+        super::two_bit_to_base(4);
+    }
+
+    #[test]
+    fn test_nucleotide_error_to_index() {
+        use super::NucleotideError;
+        assert_eq!(NucleotideError::Invalid(4).to_index(), 4);
+        assert_eq!(NucleotideError::Overflow(40).to_index(), 40);
+        assert_eq!(NucleotideError::OutOfRange(7).to_index(), 7);
+        assert_eq!(
+            Err::<(), _>(NucleotideError::Invalid(4))
+                .err()
+                .map(|e| e.to_index()),
+            Some(4)
+        );
+    }
+
+    #[test]
+    fn test_nucleotide_error_into_usize() {
+        use super::{checks, NucleotideError};
+        assert_eq!(usize::from(NucleotideError::Invalid(4)), 4);
+        assert_eq!(usize::from(NucleotideError::Overflow(40)), 40);
+        assert_eq!(usize::from(NucleotideError::OutOfRange(7)), 7);
+        assert_eq!(checks::check_dna("ACGTU").err().map(usize::from), Some(4));
+
+        // The `?` operator converts it in functions returning `Result<_, usize>`.
+        fn check_dna_index(dna: &str) -> Result<(), usize> {
+            checks::check_dna(dna)?;
+            Ok(())
+        }
+        assert_eq!(check_dna_index("ACX"), Err(2));
+    }
+
+    #[test]
+    fn test_nucleotide_error_display() {
+        use super::NucleotideError;
+        assert_eq!(
+            format!("{}", NucleotideError::Invalid(4)),
+            "invalid nucleotide at index 4"
+        );
+        assert_eq!(
+            format!("{}", NucleotideError::Overflow(40)),
+            "storage overflow at index 40"
+        );
+        assert_eq!(
+            format!("{}", NucleotideError::OutOfRange(7)),
+            "index 7 out of range"
+        );
+    }
+
+    #[test]
+    fn test_nucleotide_char() {
+        use super::Nucleotide;
+        for (c, nucleotide) in [
+            ('A', Nucleotide::A),
+            ('C', Nucleotide::C),
+            ('G', Nucleotide::G),
+            ('U', Nucleotide::U),
+        ] {
+            assert_eq!(Nucleotide::try_from(c), Ok(nucleotide));
+            assert_eq!(char::from(nucleotide), c);
+        }
+        assert_eq!(Nucleotide::try_from('T'), Err('T'));
+    }
+
+    #[test]
+    fn test_transcribe_slice() {
+        let mut out = [0u8; 6];
+        assert_eq!(super::transcribe_slice(b"GCTA", &mut out), Ok(4));
+        assert_eq!(&out[..4], b"CGAU");
+        assert_eq!(super::transcribe_slice(b"", &mut out), Ok(0));
+    }
+
+    #[test]
+    fn test_transcribe_slice_invalid() {
+        let mut out = [0u8; 6];
+        assert_eq!(super::transcribe_slice(b"GCUA", &mut out), Err(2));
+        assert_eq!(super::transcribe_slice("GCé".as_bytes(), &mut out), Err(2));
+    }
+
+    #[test]
+    fn test_transcribe_slice_overflow() {
+        let mut out = [0u8; 3];
+        assert_eq!(super::transcribe_slice(b"GCTA", &mut out), Err(3));
+        assert_eq!(super::transcribe_slice(b"GCTA", &mut []), Err(0));
+    }
+
+    #[test]
+    fn test_reverse_complement_into() {
+        let mut out = [0u8; 6];
+        assert_eq!(super::reverse_complement_into(b"ACGTT", &mut out), Ok(5));
+        assert_eq!(&out[..5], b"AACGT");
+        assert_eq!(super::reverse_complement_into(b"", &mut out), Ok(0));
+    }
+
+    #[test]
+    fn test_reverse_complement_into_errors() {
+        let mut out = [0u8; 6];
+        assert_eq!(super::reverse_complement_into(b"ACUT", &mut out), Err(2));
+        assert_eq!(
+            super::reverse_complement_into(b"ACGT", &mut out[..3]),
+            Err(3)
+        );
+    }
+
+    #[test]
+    fn test_codon_index() {
+        assert_eq!(super::codon_index(['U', 'U', 'U']), 0);
+        assert_eq!(super::codon_index(['A', 'U', 'G']), 35);
+        assert_eq!(super::codon_index(['G', 'G', 'G']), 63);
+    }
+
+    #[test]
+    fn test_is_start_codon() {
+        assert!(super::is_start_codon(['A', 'U', 'G']));
+        assert!(!super::is_start_codon(['A', 'U', 'A']));
+        assert!(!super::is_start_codon(['U', 'A', 'A']));
+    }
+
+    #[test]
+    fn test_is_stop_codon() {
+        assert!(super::is_stop_codon(['U', 'A', 'A']));
+        assert!(super::is_stop_codon(['U', 'A', 'G']));
+        assert!(super::is_stop_codon(['U', 'G', 'A']));
+        assert!(!super::is_stop_codon(['A', 'U', 'G']));
+        assert!(!super::is_stop_codon(['U', 'G', 'G']));
+    }
+
+    /// The stop codons are exactly the codons that don't translate to an amino acid.
+    #[test]
+    fn test_is_stop_codon_consistent_with_translate_codon() {
+        let bases = ['U', 'C', 'A', 'G'];
+        for first in bases {
+            for second in bases {
+                for third in bases {
+                    let codon = [first, second, third];
+                    assert_eq!(
+                        super::is_stop_codon(codon),
+                        super::translate_codon(codon).is_none()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_translate_codon() {
+        assert_eq!(super::translate_codon(['A', 'U', 'G']), Some('M'));
+        assert_eq!(super::translate_codon(['U', 'U', 'U']), Some('F'));
+        assert_eq!(super::translate_codon(['G', 'G', 'G']), Some('G'));
+        assert_eq!(super::translate_codon(['U', 'G', 'G']), Some('W'));
+        assert_eq!(super::translate_codon(['U', 'A', 'A']), None);
+        assert_eq!(super::translate_codon(['U', 'A', 'G']), None);
+        assert_eq!(super::translate_codon(['U', 'G', 'A']), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_translate_codon_panic_invalid() {
+        super::translate_codon(['A', 'T', 'G']);
+    }
+
+    #[test]
+    fn test_hamming() {
+        assert_eq!(super::hamming("CGAU".chars(), "CGAU".chars()), Some(0));
+        assert_eq!(super::hamming("CGAU".chars(), "CUAA".chars()), Some(2));
+        assert_eq!(super::hamming("CGAU".chars(), "CGA".chars()), None);
+        assert_eq!(super::hamming("".chars(), "".chars()), Some(0));
+    }
+
+    #[test]
+    fn test_hamming_transcription_iter() {
+        let transcribed = "GCTA".chars().map(super::dna_to_rna);
+        assert_eq!(super::hamming(transcribed, "CGAA".chars()), Some(1));
+    }
+
+    #[test]
+    fn test_levenshtein() {
+        let mut scratch = [0usize; 8];
+        assert_eq!(super::levenshtein("ACGT", "AGT", &mut scratch), 1);
+        assert_eq!(super::levenshtein("AGT", "ACGT", &mut scratch), 1);
+        assert_eq!(super::levenshtein("ACGT", "ACGT", &mut scratch), 0);
+        assert_eq!(super::levenshtein("", "ACG", &mut scratch), 3);
+        assert_eq!(super::levenshtein("ACG", "", &mut scratch), 3);
+        assert_eq!(super::levenshtein("GATTACA", "GCATGCU", &mut scratch), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_levenshtein_panic_small_scratch() {
+        let mut scratch = [0usize; 3];
+        super::levenshtein("ACGT", "AGT", &mut scratch);
+    }
 }
@@ -2,11 +2,13 @@
 #![no_std]
 extern crate alloc;
 
-use alloc::{borrow::ToOwned, string::String};
-use core::fmt::Debug;
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::fmt::{self, Debug, Formatter, LowerHex};
+use core::iter::Sum;
+use core::ops::Add;
 // #[cfg(test)]
 // use test_harness;
-use utils::{checks, DnaTrait, OurResult, RnaTrait};
+use utils::{checks, DnaTrait, Nucleotide, NucleotideCounts, NucleotideError, OurResult, RnaTrait};
 
 /// DNA (DNA nucleotide sequence).  
 ///
@@ -30,6 +32,125 @@ pub struct Dna(String);
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Rna(String);
 
+/// Protein: a sequence of amino acids, each represented by its single letter code.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Protein(String);
+
+/// RNA with nucleotides packed 2 bits each (A = 0, C = 1, G = 2, U = 3), four nucleotides per byte,
+/// in the same layout as [`Dna::two_bit_encode`]. Create it with [`Rna::pack`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PackedRna {
+    bytes: Vec<u8>,
+    /// Number of nucleotides.
+    len: usize,
+}
+
+impl PackedRna {
+    /// Number of nucleotides.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The packed bytes. Any unused bits in the last byte are zero.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn unpack(&self) -> Rna {
+        let rna = (0..self.len)
+            .map(|i| {
+                let code = (self.bytes[i / 4] >> (6 - 2 * (i % 4))) & 0b11;
+                utils::two_bit_to_base(code)
+            })
+            .collect();
+        Rna(rna)
+    }
+}
+
+/// Raw packed bytes in hex, two digits per byte. For debugging.
+impl LowerHex for PackedRna {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for b in &self.bytes {
+            write!(f, "{b:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Protein {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Number of G and C nucleotides, out of a `total` number of nucleotides. Combine counts of
+/// several sequences with `+` or [`Iterator::sum`]. See [`Rna::gc_count`].
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct GcCount {
+    pub gc: usize,
+    pub total: usize,
+}
+
+impl GcCount {
+    /// Ratio of G and C nucleotides, between `0.0` and `1.0`. Return `0.0` if `total` is zero.
+    pub fn ratio(&self) -> f32 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.gc as f32 / self.total as f32
+        }
+    }
+}
+
+impl Add for GcCount {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            gc: self.gc + other.gc,
+            total: self.total + other.total,
+        }
+    }
+}
+
+impl Sum for GcCount {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+/// Streaming G and C ratio (for example, per window of a long stream of nucleotides). It counts
+/// with integers internally, so (unlike summing `f32` ratios) it doesn't drift, no matter how many
+/// nucleotides are pushed.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct GcAccumulator(GcCount);
+
+impl GcAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count one more nucleotide.
+    pub fn push(&mut self, c: char) {
+        self.0.gc += usize::from(c == 'G' || c == 'C');
+        self.0.total += 1;
+    }
+
+    /// Counts so far.
+    pub fn count(&self) -> GcCount {
+        self.0
+    }
+
+    /// Ratio of G and C nucleotides pushed so far. See [`GcCount::ratio`].
+    pub fn ratio(&self) -> f32 {
+        self.0.ratio()
+    }
+}
+
 impl<'a> DnaTrait<'a, Rna> for Dna {
     /// Create a new [`Dna`] instance with given DNA nucleotides. If `dna` is valid, return  
     /// [`Some(Dna)`](Some<Dna>) containing the new instance. On error return [`Err`] with a 0-based
@@ -39,6 +160,10 @@ impl<'a> DnaTrait<'a, Rna> for Dna {
         Ok(Self(dna.to_owned()))
     }
 
+    fn as_str(&self) -> &str {
+        &self.0
+    }
+
     /// Create an [`Rna`] instance based on `self`. Transcript all nucleotides to RNA (and store
     /// them in the result [`Rna`] instance).
     fn into_rna(&self) -> Rna {
@@ -51,6 +176,153 @@ impl<'a> DnaTrait<'a, Rna> for Dna {
     }
 }
 
+/// DNA nucleotides, indexed by their 2-bit code. See [`Dna::two_bit_decode`].
+const TWO_BIT_DNA: &[u8; 4] = b"ACGT";
+
+impl Dna {
+    /// Like [`Dna::new`], but also require exactly `expected_len` nucleotides (for protocols
+    /// expecting a fixed-length sequence). On error return [`Err`] with a 0-based index of the
+    /// first incorrect character, or (if all characters are correct but the length differs) with
+    /// the actual length.
+    pub fn new_exact(dna: &str, expected_len: usize) -> Result<Dna, usize> {
+        let dna = Dna::new(dna)?;
+        // DNA nucleotides are ASCII, so there is one byte per nucleotide.
+        let len = dna.0.len();
+        if len == expected_len {
+            Ok(dna)
+        } else {
+            Err(len)
+        }
+    }
+
+    /// Encode nucleotides with 2 bits each (A = 0, C = 1, G = 2, T = 3), four nucleotides per byte
+    /// written to `out`. The first nucleotide goes to the most significant bits. Any unused bits in
+    /// the last byte are zero. On success return the number of bytes written. On error return
+    /// [`Err`] with:
+    /// - [`NucleotideError::Overflow`] with `out.len()` if `out` is too short (then nothing is
+    ///   written), or
+    /// - [`NucleotideError::Invalid`] with a 0-based index of the first N (any nucleotide, with
+    ///   feature `utils/iupac`), which has no 2-bit code. Then `out` may have been partially
+    ///   written to.
+    ///
+    /// See [`Dna::two_bit_decode`].
+    pub fn two_bit_encode(&self, out: &mut [u8]) -> OurResult<usize> {
+        let num_bytes = self.0.len().div_ceil(4);
+        if num_bytes > out.len() {
+            return Err(NucleotideError::Overflow(out.len()));
+        }
+        out[..num_bytes].fill(0);
+        for (i, c) in self.0.chars().enumerate() {
+            let code = utils::base_to_2bit(c).map_err(|_| NucleotideError::Invalid(i))?;
+            out[i / 4] |= code << (6 - 2 * (i % 4));
+        }
+        Ok(num_bytes)
+    }
+
+    /// Decode `len` nucleotides encoded by [`Dna::two_bit_encode`]. On error (if `packed` is too
+    /// short for `len` nucleotides) return [`Err`] with `packed.len()`.
+    pub fn two_bit_decode(packed: &[u8], len: usize) -> Result<Dna, usize> {
+        if len.div_ceil(4) > packed.len() {
+            return Err(packed.len());
+        }
+        let dna = (0..len)
+            .map(|i| {
+                let code = (packed[i / 4] >> (6 - 2 * (i % 4))) & 0b11;
+                TWO_BIT_DNA[code as usize] as char
+            })
+            .collect();
+        Ok(Dna(dna))
+    }
+
+    /// Transcribe to RNA nucleotides (as ASCII bytes) written to `out`, without allocating. On
+    /// success return the number of bytes written. If `out` is too short, don't write anything,
+    /// and return [`Err`] with `out.len()`.
+    pub fn transcribe_into(&self, out: &mut [u8]) -> Result<usize, usize> {
+        // DNA nucleotides are ASCII, so there is one byte per nucleotide.
+        let len = self.0.len();
+        if len > out.len() {
+            return Err(out.len());
+        }
+        for (o, c) in out.iter_mut().zip(self.0.chars()) {
+            *o = utils::dna_to_rna(c) as u8;
+        }
+        Ok(len)
+    }
+
+    /// Transcribe each of `dnas` into the respective slot of `out` (for batch processing). Return
+    /// the number of transcribed items. If `out` is shorter than `dnas`, return
+    /// [`NucleotideError::Overflow`] with `out.len()`, and leave `out` unchanged.
+    pub fn transcribe_batch(dnas: &[Dna], out: &mut [Rna]) -> OurResult<usize> {
+        if dnas.len() > out.len() {
+            return Err(NucleotideError::Overflow(out.len()));
+        }
+        for (o, dna) in out.iter_mut().zip(dnas) {
+            *o = dna.into_rna();
+        }
+        Ok(dnas.len())
+    }
+
+    /// Like [`Dna::into_rna`], but with the resulting RNA nucleotides in reverse order (for
+    /// antisense work). This transcribes and reverses in one pass.
+    pub fn into_rna_reverse(&self) -> Rna {
+        Rna(self.0.chars().rev().map(utils::dna_to_rna).collect())
+    }
+
+    /// Find the longest contiguous run of nucleotides shared by `self` and `other`. Return
+    /// `(start in self, start in other, length)`. If several are equally long, return the one that
+    /// ends first in `self` (and then in `other`). If there is none, return `(0, 0, 0)`.
+    ///
+    /// Heapless: `scratch` is a working row of the dynamic programming table, provided by the
+    /// caller. It must have at least `other`'s length + 1 items (any extra items are left
+    /// untouched). Any previous content of those items is overwritten. [`panic`] if `scratch`
+    /// doesn't have enough space.
+    pub fn longest_common_substring(
+        &self,
+        other: &Dna,
+        scratch: &mut [usize],
+    ) -> (usize, usize, usize) {
+        // DNA nucleotides are ASCII, so we can work with bytes.
+        let (left, right) = (self.0.as_bytes(), other.0.as_bytes());
+        assert!(
+            scratch.len() > right.len(),
+            "longest_common_substring() needs scratch with at least {} items, but it has {}.",
+            right.len() + 1,
+            scratch.len()
+        );
+        // row[j + 1] is the length of the common suffix of left[..=i] and right[..=j]. Only one
+        // row of the (left.len() + 1) x (right.len() + 1) table is stored at any time.
+        let row = &mut scratch[..=right.len()];
+        row.fill(0);
+        let mut longest = (0, 0, 0);
+        for (i, &l) in left.iter().enumerate() {
+            // Value of row[j] from the previous row, before it was overwritten.
+            let mut diagonal = row[0];
+            for (j, &r) in right.iter().enumerate() {
+                let above = row[j + 1];
+                row[j + 1] = if l == r { diagonal + 1 } else { 0 };
+                diagonal = above;
+                let len = row[j + 1];
+                if len > longest.2 {
+                    longest = (i + 1 - len, j + 1 - len, len);
+                }
+            }
+        }
+        longest
+    }
+
+    /// Check that the number of nucleotides is within `min..=max` (for example, for primer design
+    /// constraints). On error return [`Err`] with the number of nucleotides.
+    pub fn validate_length(&self, min: usize, max: usize) -> Result<(), usize> {
+        // DNA nucleotides are ASCII, so there is one byte per nucleotide.
+        let len = self.0.len();
+        if (min..=max).contains(&len) {
+            Ok(())
+        } else {
+            Err(len)
+        }
+    }
+}
+
 impl<'a> RnaTrait<'a> for Rna {
     /// Create a new [`Rna`] instance with given RNA nucleotides. If `rna` is valid, return  
     /// [`Some(Rna)`](Some<Rna>) containing the new instance. On error return [`Err`] with a 0-based
@@ -60,3 +332,995 @@ impl<'a> RnaTrait<'a> for Rna {
         Ok(Self(rna.to_owned()))
     }
 }
+
+impl Rna {
+    /// Like [`Rna::new`], but accept lowercase (or mixed case) nucleotides, too. They are stored
+    /// uppercased.
+    pub fn new_normalized(rna: &str) -> OurResult<Rna> {
+        let rna = rna.to_ascii_uppercase();
+        checks::check_rna_str(&rna)?;
+        Ok(Rna(rna))
+    }
+
+    /// Reverse transcription: Create a [`Dna`] instance that [`Dna::into_rna`] would transcribe
+    /// to `self`. See [`utils::rna_to_dna`].
+    pub fn into_dna(&self) -> Dna {
+        Dna(self.0.chars().map(utils::rna_to_dna).collect())
+    }
+
+    /// Soft-mask (for example, for repeat masking interop): Return the nucleotides with ones in
+    /// any of `ranges` lowercased, and the rest uppercase. Each range is `(start, end)`, with
+    /// `end` exclusive. [`panic`] if any range is out of bounds (or if its `start > end`).
+    pub fn soft_mask(&self, ranges: &[(usize, usize)]) -> String {
+        let mut masked = self.0.clone();
+        for &(start, end) in ranges {
+            assert!(
+                start <= end && end <= masked.len(),
+                "soft_mask() range ({start}, {end}) is out of bounds of length {}.",
+                masked.len()
+            );
+            masked[start..end].make_ascii_lowercase();
+        }
+        masked
+    }
+
+    /// Copy the nucleotides (as ASCII bytes) into a fixed-size array, for example for the
+    /// `[u8; N]` based storage of heapless crates. Any trailing bytes are zero. On error (if it
+    /// doesn't fit) return [`Err`] with [`NucleotideError::Overflow`] with `N` (index of the first
+    /// nucleotide that doesn't fit).
+    pub fn to_fixed<const N: usize>(&self) -> OurResult<[u8; N]> {
+        let bytes = self.0.as_bytes();
+        if bytes.len() > N {
+            return Err(NucleotideError::Overflow(N));
+        }
+        let mut result = [0u8; N];
+        result[..bytes.len()].copy_from_slice(bytes);
+        Ok(result)
+    }
+
+    /// Pack nucleotides with 2 bits each. See [`PackedRna`]. On error return [`Err`] with
+    /// [`NucleotideError::Invalid`] with a 0-based index of the first N (any nucleotide, with
+    /// feature `utils/iupac`), which has no 2-bit code.
+    pub fn pack(&self) -> OurResult<PackedRna> {
+        let len = self.0.len();
+        let mut bytes = alloc::vec![0u8; len.div_ceil(4)];
+        for (i, c) in self.0.chars().enumerate() {
+            let code = utils::base_to_2bit(c).map_err(|_| NucleotideError::Invalid(i))?;
+            bytes[i / 4] |= code << (6 - 2 * (i % 4));
+        }
+        Ok(PackedRna { bytes, len })
+    }
+
+    /// Iterate over `self`'s nucleotides as typed [`Nucleotide`]s.
+    pub fn bases(&self) -> impl Iterator<Item = Nucleotide> + '_ {
+        self.0
+            .chars()
+            .map(|c| Nucleotide::try_from(c).expect("RNA nucleotide"))
+    }
+
+    /// Create a new [`Rna`] with all leading and trailing occurrences of `base` removed (for
+    /// example, for adapter trimming).
+    pub fn trim_matches(&self, base: char) -> Rna {
+        Rna(self.0.trim_matches(base).to_owned())
+    }
+
+    /// Number of each nucleotide.
+    pub fn counts(&self) -> NucleotideCounts {
+        utils::validate_and_count(&self.0).expect("RNA nucleotides")
+    }
+
+    /// Ratio of each nucleotide, in order A, C, G, U. They add up to `1.0`, unless empty (then
+    /// they are all `0.0`), or unless there is any N (any nucleotide, with feature `utils/iupac`),
+    /// which isn't included.
+    pub fn nucleotide_frequencies(&self) -> [f32; 4] {
+        let NucleotideCounts { a, c, g, u, .. } = self.counts();
+        let len = self.0.len();
+        if len == 0 {
+            return [0.0; 4];
+        }
+        [a, c, g, u].map(|count| count as f32 / len as f32)
+    }
+
+    /// Number of G and C nucleotides.
+    fn gc(&self) -> usize {
+        self.0.chars().filter(|&c| c == 'G' || c == 'C').count()
+    }
+
+    /// Number of G and C nucleotides, together with the total number of nucleotides.
+    pub fn gc_count(&self) -> GcCount {
+        GcCount {
+            gc: self.gc(),
+            total: self.0.len(),
+        }
+    }
+
+    /// Ratio of G and C nucleotides, between `0.0` and `1.0`. Return `0.0` if empty.
+    pub fn gc_content(&self) -> f32 {
+        self.gc_count().ratio()
+    }
+
+    /// Like [`Rna::gc_content`], but as a percentage (`0` to `100`), rounded half up. Computed
+    /// with integers only, so it doesn't need floating point (which is slow on targets without an
+    /// FPU). Return `0` if empty.
+    pub fn gc_percent(&self) -> u8 {
+        let len = self.0.len();
+        (self.gc() * 100 + len / 2).checked_div(len).unwrap_or(0) as u8
+    }
+
+    /// Whether `pat` occurs in `self` as a contiguous subsequence. An empty `pat` always occurs.
+    pub fn contains(&self, pat: &str) -> bool {
+        self.0.contains(pat)
+    }
+
+    /// Whether `self` equals `other` reversed (without complementing).
+    pub fn is_reverse_of(&self, other: &Rna) -> bool {
+        self.0.chars().eq(other.0.chars().rev())
+    }
+
+    /// Whether `self`'s nucleotides occur in `other` in the same order, but not necessarily
+    /// contiguous (for example, a motif with gaps). See also [`Rna::contains`].
+    pub fn is_subsequence_of(&self, other: &str) -> bool {
+        let mut other_chars = other.chars();
+        self.0.chars().all(|c| other_chars.any(|o| o == c))
+    }
+
+    /// Iterate over runs of consecutive identical nucleotides, each as (nucleotide, run length).
+    pub fn runs(&self) -> impl Iterator<Item = (char, usize)> + '_ {
+        self.0
+            .as_bytes()
+            .chunk_by(|a, b| a == b)
+            .map(|run| (run[0] as char, run.len()))
+    }
+
+    /// Iterate over (overlapping) pairs of adjacent nucleotides (for dinucleotide analysis).
+    pub fn dinucleotides(&self) -> impl Iterator<Item = (char, char)> + '_ {
+        self.0
+            .as_bytes()
+            .windows(2)
+            .map(|pair| (pair[0] as char, pair[1] as char))
+    }
+
+    /// Find the first start codon (AUG) in any reading frame, and return the index of its first
+    /// nucleotide. Return [`None`] if there is none.
+    pub fn find_start_codon(&self) -> Option<usize> {
+        self.0.find("AUG")
+    }
+
+    /// Iterate over codons in reading frame 0, each with the index of its first nucleotide. Any
+    /// incomplete trailing codon is skipped.
+    pub fn codons_indexed(&self) -> impl Iterator<Item = (usize, [char; 3])> + '_ {
+        self.0.as_bytes().as_chunks::<3>().0.iter().enumerate().map(
+            |(i, &[first, second, third])| (3 * i, [first as char, second as char, third as char]),
+        )
+    }
+
+    /// Count codons in reading frame 0 (for codon bias analysis). Each codon's count is at its
+    /// [`utils::codon_index`]: `16 * first + 4 * second + third`, where each base is numbered in
+    /// order U, C, A, G. For example, UUU is at `0`, AUG is at `35` and GGG is at `63`. Any
+    /// incomplete trailing codon is skipped, and so is any codon containing N (any nucleotide, with
+    /// feature `utils/iupac`).
+    pub fn codon_usage(&self) -> [usize; 64] {
+        let mut usage = [0; 64];
+        for (_, codon) in self.codons_indexed() {
+            if codon.contains(&'N') {
+                continue;
+            }
+            usage[utils::codon_index(codon)] += 1;
+        }
+        usage
+    }
+
+    /// Whether `self` is a complete open reading frame (ORF): Its length is divisible by 3, it
+    /// starts with a start codon (AUG), it ends with a stop codon, and it has no other in-frame
+    /// stop codon.
+    pub fn is_valid_orf(&self) -> bool {
+        let len = self.0.len();
+        len.is_multiple_of(3)
+            && len >= 6
+            && self.codons_indexed().all(|(i, codon)| match i {
+                0 => utils::is_start_codon(codon),
+                _ => utils::is_stop_codon(codon) == (i == len - 3),
+            })
+    }
+
+    /// Find the longest open reading frame (ORF) in any of the three reading frames, and translate
+    /// it to a [`Protein`]. An ORF starts with a start codon (AUG) and it ends with the first
+    /// in-frame stop codon (which is not translated). Return [`None`] if there is no such ORF. If
+    /// several ORFs are equally long, return the first one found (scanning frames in order 0, 1
+    /// and 2).
+    pub fn longest_orf_protein(&self) -> Option<Protein> {
+        // Nucleotides are ASCII, so we can work with bytes.
+        let bytes = self.0.as_bytes();
+        let codon_at = |i: usize| [bytes[i] as char, bytes[i + 1] as char, bytes[i + 2] as char];
+        // Start and (exclusive) end index of the longest ORF so far.
+        let mut longest: Option<(usize, usize)> = None;
+        for frame in 0..3 {
+            let mut orf_start = None;
+            for i in (frame..bytes.len().saturating_sub(2)).step_by(3) {
+                let codon = codon_at(i);
+                match orf_start {
+                    None if utils::is_start_codon(codon) => orf_start = Some(i),
+                    Some(start) if utils::is_stop_codon(codon) => {
+                        if longest.is_none_or(|(l_start, l_end)| i - start > l_end - l_start) {
+                            longest = Some((start, i));
+                        }
+                        orf_start = None;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        longest.map(|(start, end)| {
+            let amino_acids = (start..end)
+                .step_by(3)
+                .map(|i| utils::translate_codon(codon_at(i)).expect("amino acid"))
+                .collect();
+            Protein(amino_acids)
+        })
+    }
+
+    /// Translate codons in reading frame 0 to amino acids (as single letters), up to the first
+    /// stop codon (which is not translated), or to the end. On error (if there is an incomplete
+    /// trailing codon before any stop codon) return [`Err`] with [`NucleotideError::OutOfRange`]
+    /// with the index of its first nucleotide: its nucleotides are valid, but the codon runs past
+    /// the end.
+    pub fn protein_string(&self) -> OurResult<String> {
+        let mut protein = String::new();
+        for (_, codon) in self.codons_indexed() {
+            match utils::translate_codon(codon) {
+                Some(amino_acid) => protein.push(amino_acid),
+                None => return Ok(protein),
+            }
+        }
+        let complete_len = self.0.len() / 3 * 3;
+        if complete_len < self.0.len() {
+            return Err(NucleotideError::OutOfRange(complete_len));
+        }
+        Ok(protein)
+    }
+}
+
+impl PartialEq<str> for Dna {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+impl PartialEq<&str> for Dna {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<str> for Rna {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+impl PartialEq<&str> for Rna {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<Vec<char>> for Rna {
+    fn eq(&self, other: &Vec<char>) -> bool {
+        self.0.chars().eq(other.iter().copied())
+    }
+}
+
+impl PartialEq<Dna> for str {
+    fn eq(&self, other: &Dna) -> bool {
+        other == self
+    }
+}
+impl PartialEq<Dna> for &str {
+    fn eq(&self, other: &Dna) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<Rna> for str {
+    fn eq(&self, other: &Rna) -> bool {
+        other == self
+    }
+}
+impl PartialEq<Rna> for &str {
+    fn eq(&self, other: &Rna) -> bool {
+        other == self
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl Rna {
+    /// Score of a pair of identical nucleotides in [`Rna::global_align`].
+    const ALIGN_MATCH: i32 = 1;
+    /// Score of a pair of different nucleotides in [`Rna::global_align`].
+    const ALIGN_MISMATCH: i32 = -1;
+
+    /// Global alignment of `self` and `other` (Needleman-Wunsch). Return both sequences aligned
+    /// (with `-` for gaps), and the alignment score. A match scores `1`, a mismatch scores `-1`
+    /// and each gap scores `gap` (normally negative).
+    ///
+    /// Heapless: `scratch` is the score matrix provided by the caller. It must have at least
+    /// `(self.len() + 1) * (other.len() + 1)` items. Const generic `N` is the capacity of the
+    /// result strings. It must be at least `self.len() + other.len()`. [`panic`] if either is
+    /// insufficient.
+    pub fn global_align<const N: usize>(
+        &self,
+        other: &Rna,
+        gap: i32,
+        scratch: &mut [i32],
+    ) -> (heapless::String<N>, heapless::String<N>, i32) {
+        let (left, right) = (self.0.as_bytes(), other.0.as_bytes());
+        let width = right.len() + 1;
+        assert!(
+            scratch.len() >= (left.len() + 1) * width,
+            "global_align() needs scratch with at least {} items, but it has {}.",
+            (left.len() + 1) * width,
+            scratch.len()
+        );
+        let pair_score = |l: u8, r: u8| {
+            if l == r {
+                Self::ALIGN_MATCH
+            } else {
+                Self::ALIGN_MISMATCH
+            }
+        };
+        for i in 0..=left.len() {
+            for j in 0..=right.len() {
+                scratch[i * width + j] = match (i, j) {
+                    (0, _) => gap * j as i32,
+                    (_, 0) => gap * i as i32,
+                    _ => (scratch[(i - 1) * width + j - 1] + pair_score(left[i - 1], right[j - 1]))
+                        .max(scratch[(i - 1) * width + j] + gap)
+                        .max(scratch[i * width + j - 1] + gap),
+                };
+            }
+        }
+        let score = scratch[left.len() * width + right.len()];
+
+        // Trace back from the end, so the aligned nucleotides come in reverse order.
+        let mut left_aligned = heapless::Vec::<u8, N>::new();
+        let mut right_aligned = heapless::Vec::<u8, N>::new();
+        let (mut i, mut j) = (left.len(), right.len());
+        while i > 0 || j > 0 {
+            let current = scratch[i * width + j];
+            let (l, r) = if i > 0
+                && j > 0
+                && current
+                    == scratch[(i - 1) * width + j - 1] + pair_score(left[i - 1], right[j - 1])
+            {
+                i -= 1;
+                j -= 1;
+                (left[i], right[j])
+            } else if i > 0 && current == scratch[(i - 1) * width + j] + gap {
+                i -= 1;
+                (left[i], b'-')
+            } else {
+                j -= 1;
+                (b'-', right[j])
+            };
+            left_aligned.push(l).expect("capacity N for aligned RNA");
+            right_aligned.push(r).expect("capacity N for aligned RNA");
+        }
+        left_aligned.reverse();
+        right_aligned.reverse();
+        let to_string =
+            |aligned| heapless::String::from_utf8(aligned).expect("ASCII nucleotides or gaps");
+        (to_string(left_aligned), to_string(right_aligned), score)
+    }
+
+    /// Create a new [`Rna`] from only the RNA nucleotides in `s`, dropping any other characters
+    /// (for quality control reporting). Return it together with the 0-based (char) indices of the
+    /// dropped characters. Only the first 16 dropped indices are recorded.
+    pub fn sanitize(s: &str) -> (Rna, heapless::Vec<usize, 16>) {
+        let mut removed = heapless::Vec::new();
+        let mut rna = String::with_capacity(s.len());
+        for (i, c) in s.chars().enumerate() {
+            if Nucleotide::try_from(c).is_ok() {
+                rna.push(c);
+            } else {
+                // If full, ignore any further indices (as documented).
+                let _ = removed.push(i);
+            }
+        }
+        (Rna(rna), removed)
+    }
+
+    /// Count all (overlapping) k-mers (subsequences of `k` nucleotides), adding their frequencies
+    /// to any counts already in `map`. Const generic `K` is the capacity of the keys. [`panic`] if
+    /// `k` is zero, or if it's more than `K`.
+    ///
+    /// On error (if `map` is full) return [`Err`] with [`NucleotideError::Overflow`] with a 0-based
+    /// index of the first k-mer that could not be counted. Any k-mers before it have been counted.
+    pub fn kmer_counts_into<const K: usize, const CAP: usize>(
+        &self,
+        k: usize,
+        map: &mut heapless::FnvIndexMap<heapless::String<K>, usize, CAP>,
+    ) -> OurResult<()> {
+        assert!(
+            k > 0 && k <= K,
+            "kmer_counts_into() needs k between 1 and {K}, but it is {k}."
+        );
+        for (i, kmer) in self.0.as_bytes().windows(k).enumerate() {
+            let kmer = core::str::from_utf8(kmer).expect("ASCII nucleotides");
+            let key = heapless::String::try_from(kmer).expect("k-mer within capacity");
+            if let Some(count) = map.get_mut(&key) {
+                *count += 1;
+            } else {
+                map.insert(key, 1)
+                    .map_err(|_| NucleotideError::Overflow(i))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Dna, GcAccumulator, GcCount, Protein, Rna};
+    use alloc::borrow::ToOwned;
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use utils::{DnaTrait, Nucleotide, NucleotideError, RnaTrait};
+
+    #[test]
+    fn test_eq_str() {
+        assert_eq!(Dna::new("ACGT").unwrap(), "ACGT");
+        assert_eq!(Dna::new("ACGT").unwrap(), *"ACGT");
+        assert_ne!(Dna::new("ACGT").unwrap(), "ACG");
+        assert_eq!(Rna::new("CGAU").unwrap(), "CGAU");
+        assert_eq!(Rna::new("CGAU").unwrap(), *"CGAU");
+        assert_ne!(Rna::new("CGAU").unwrap(), "CGAA");
+    }
+
+    #[test]
+    fn test_eq_vec_char() {
+        let rna = Rna::new("CGAU").unwrap();
+        assert_eq!(rna, vec!['C', 'G', 'A', 'U']);
+        assert_ne!(rna, vec!['C', 'G', 'A']);
+        assert_ne!(rna, vec!['C', 'G', 'A', 'U', 'U']);
+        assert_eq!(Rna::new("").unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn test_str_eq() {
+        assert_eq!("ACGT", Dna::new("ACGT").unwrap());
+        assert_eq!(*"ACGT", Dna::new("ACGT").unwrap());
+        assert_ne!("ACG", Dna::new("ACGT").unwrap());
+        assert_eq!("CGAU", Rna::new("CGAU").unwrap());
+        assert_eq!(*"CGAU", Rna::new("CGAU").unwrap());
+        assert_ne!("CGAA", Rna::new("CGAU").unwrap());
+    }
+
+    #[test]
+    fn test_two_bit_round_trip() {
+        let dna = Dna::new("ACGTACGT").unwrap();
+        let mut packed = [0u8; 2];
+        assert_eq!(dna.two_bit_encode(&mut packed), Ok(2));
+        assert_eq!(packed, [0b00_01_10_11, 0b00_01_10_11]);
+        assert_eq!(Dna::two_bit_decode(&packed, 8), Ok(dna));
+
+        let dna = Dna::new("TTGCA").unwrap();
+        let mut packed = [0xFFu8; 3];
+        assert_eq!(dna.two_bit_encode(&mut packed), Ok(2));
+        assert_eq!(packed, [0b11_11_10_01, 0b00_00_00_00, 0xFF]);
+        assert_eq!(Dna::two_bit_decode(&packed, 5), Ok(dna));
+    }
+
+    #[test]
+    fn test_two_bit_errors() {
+        let dna = Dna::new("ACGTA").unwrap();
+        assert_eq!(
+            dna.two_bit_encode(&mut [0u8; 1]),
+            Err(NucleotideError::Overflow(1))
+        );
+        assert_eq!(Dna::two_bit_decode(&[0u8; 1], 5), Err(1));
+    }
+
+    #[test]
+    fn test_parse_many() {
+        let parsed = utils::parse_many::<Rna>("CGAU AUGC").collect::<Vec<_>>();
+        assert_eq!(
+            parsed,
+            [Ok(Rna::new("CGAU").unwrap()), Ok(Rna::new("AUGC").unwrap())]
+        );
+
+        let parsed = utils::parse_many::<Rna>("CGAU\n CGTA\tAUGC\n").collect::<Vec<_>>();
+        assert_eq!(
+            parsed,
+            [
+                Ok(Rna::new("CGAU").unwrap()),
+                Err(NucleotideError::Invalid(2)),
+                Ok(Rna::new("AUGC").unwrap())
+            ]
+        );
+        assert_eq!(utils::parse_many::<Rna>(" \n").next(), None);
+    }
+
+    #[test]
+    fn test_new_normalized() {
+        assert_eq!(Rna::new_normalized("cgau"), Ok(Rna::new("CGAU").unwrap()));
+        assert_eq!(Rna::new_normalized("CgAu"), Ok(Rna::new("CGAU").unwrap()));
+        assert_eq!(
+            Rna::new_normalized("cgtu"),
+            Err(NucleotideError::Invalid(2))
+        );
+        assert_eq!(Rna::new("cgau"), Err(NucleotideError::Invalid(0)));
+    }
+
+    #[test]
+    fn test_soft_mask() {
+        let rna = Rna::new("ACGU").unwrap();
+        assert_eq!(rna.soft_mask(&[(1, 3)]), "AcgU");
+        assert_eq!(rna.soft_mask(&[(0, 1), (3, 4)]), "aCGu");
+        assert_eq!(rna.soft_mask(&[]), "ACGU");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_soft_mask_out_of_bounds() {
+        Rna::new("ACGU").unwrap().soft_mask(&[(2, 5)]);
+    }
+
+    #[test]
+    fn test_to_fixed() {
+        let rna = Rna::new("CGAU").unwrap();
+        assert_eq!(rna.to_fixed::<6>(), Ok(*b"CGAU\0\0"));
+        assert_eq!(rna.to_fixed::<4>(), Ok(*b"CGAU"));
+        assert_eq!(rna.to_fixed::<3>(), Err(NucleotideError::Overflow(3)));
+    }
+
+    #[test]
+    fn test_packed_rna() {
+        let rna = Rna::new("ACGUA").unwrap();
+        let packed = rna.pack().unwrap();
+        assert_eq!(packed.len(), 5);
+        assert_eq!(packed.as_bytes(), [0b0001_1011, 0b0000_0000]);
+        assert_eq!(format!("{:x}", packed), "1b00");
+        assert_eq!(packed.unpack(), rna);
+
+        let empty = Rna::new("").unwrap().pack().unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(format!("{:x}", empty), "");
+    }
+
+    #[test]
+    fn test_transcribe_into() {
+        let dna = Dna::new("GCTA").unwrap();
+        let mut out = [0u8; 6];
+        assert_eq!(dna.transcribe_into(&mut out), Ok(4));
+        assert_eq!(&out, b"CGAU\0\0");
+    }
+
+    #[test]
+    fn test_transcribe_into_overflow() {
+        let dna = Dna::new("GCTA").unwrap();
+        let mut out = [0u8; 3];
+        assert_eq!(dna.transcribe_into(&mut out), Err(3));
+        assert_eq!(out, [0u8; 3]);
+    }
+
+    #[test]
+    fn test_gc_accumulator() {
+        let mut acc = GcAccumulator::new();
+        assert_eq!(acc.ratio(), 0.0);
+
+        const TOTAL: usize = 1_000_000;
+        let pattern = ['G', 'A', 'U', 'C', 'A', 'U', 'G'];
+        let mut gc = 0usize;
+        for c in pattern.iter().cycle().take(TOTAL) {
+            gc += usize::from(*c == 'G' || *c == 'C');
+            acc.push(*c);
+        }
+        assert_eq!(acc.count(), GcCount { gc, total: TOTAL });
+        let exact = gc as f64 / TOTAL as f64;
+        assert!((f64::from(acc.ratio()) - exact).abs() <= f64::from(f32::EPSILON));
+    }
+
+    #[test]
+    fn test_transcribe_batch() {
+        let dnas = [
+            Dna::new("GCTA").unwrap(),
+            Dna::new("").unwrap(),
+            Dna::new("TTA").unwrap(),
+        ];
+        let mut out = [
+            Rna::new("").unwrap(),
+            Rna::new("").unwrap(),
+            Rna::new("").unwrap(),
+        ];
+        assert_eq!(Dna::transcribe_batch(&dnas, &mut out), Ok(3));
+        assert_eq!(out[0], "CGAU");
+        assert_eq!(out[1], "");
+        assert_eq!(out[2], "AAU");
+
+        let mut short = [Rna::new("").unwrap()];
+        assert_eq!(
+            Dna::transcribe_batch(&dnas, &mut short),
+            Err(NucleotideError::Overflow(1))
+        );
+        assert_eq!(short[0], "");
+    }
+
+    #[test]
+    fn test_new_exact() {
+        assert_eq!(Dna::new_exact("ACGT", 4), Ok(Dna::new("ACGT").unwrap()));
+        assert_eq!(Dna::new_exact("ACGT", 5), Err(4));
+        assert_eq!(Dna::new_exact("ACGT", 3), Err(4));
+        assert_eq!(Dna::new_exact("ACUT", 4), Err(2));
+        assert_eq!(Dna::new_exact("", 0), Ok(Dna::new("").unwrap()));
+    }
+
+    #[test]
+    fn test_into_rna_reverse() {
+        let dna = Dna::new("GGCTA").unwrap();
+        let reversed = dna.into_rna().0.chars().rev().collect::<String>();
+        assert_eq!(dna.into_rna_reverse(), Rna::new(&reversed).unwrap());
+        assert_eq!(dna.into_rna_reverse(), "UAGCC");
+    }
+
+    #[test]
+    fn test_into_dna() {
+        let dna = Dna::new("ACGT").unwrap();
+        assert_eq!(dna.into_rna().into_dna(), dna);
+        assert_eq!(Rna::new("UGCA").unwrap().into_dna(), dna);
+        assert_eq!(Rna::new("").unwrap().into_dna(), Dna::new("").unwrap());
+    }
+
+    #[test]
+    fn test_longest_common_substring() {
+        let mut scratch = [0usize; 10];
+        let dna = Dna::new("GATTACA").unwrap();
+        assert_eq!(
+            dna.longest_common_substring(&Dna::new("CTTACAG").unwrap(), &mut scratch),
+            (2, 1, 5)
+        );
+        assert_eq!(
+            dna.longest_common_substring(&Dna::new("GGG").unwrap(), &mut scratch),
+            (0, 0, 1)
+        );
+        assert_eq!(
+            dna.longest_common_substring(&Dna::new("TTT").unwrap(), &mut scratch),
+            (2, 0, 2)
+        );
+        assert_eq!(
+            dna.longest_common_substring(&Dna::new("").unwrap(), &mut scratch),
+            (0, 0, 0)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_longest_common_substring_panic_small_scratch() {
+        let mut scratch = [0usize; 3];
+        let dna = Dna::new("GATTACA").unwrap();
+        dna.longest_common_substring(&Dna::new("TTA").unwrap(), &mut scratch);
+    }
+
+    #[test]
+    fn test_validate_length() {
+        let dna = Dna::new("ACGTA").unwrap();
+        assert_eq!(dna.validate_length(5, 5), Ok(()));
+        assert_eq!(dna.validate_length(2, 10), Ok(()));
+        assert_eq!(dna.validate_length(6, 10), Err(5));
+        assert_eq!(dna.validate_length(1, 4), Err(5));
+    }
+
+    #[test]
+    fn test_bases() {
+        let rna = Rna::new("ACGU").unwrap();
+        let bases = rna.bases().collect::<Vec<_>>();
+        assert_eq!(
+            bases,
+            [Nucleotide::A, Nucleotide::C, Nucleotide::G, Nucleotide::U]
+        );
+    }
+
+    #[test]
+    fn test_runs() {
+        let rna = Rna::new("AACGGG").unwrap();
+        assert_eq!(
+            rna.runs().collect::<Vec<_>>(),
+            [('A', 2), ('C', 1), ('G', 3)]
+        );
+        assert_eq!(Rna::new("").unwrap().runs().next(), None);
+    }
+
+    #[test]
+    fn test_dinucleotides() {
+        let rna = Rna::new("ACGU").unwrap();
+        assert_eq!(
+            rna.dinucleotides().collect::<Vec<_>>(),
+            [('A', 'C'), ('C', 'G'), ('G', 'U')]
+        );
+        assert_eq!(Rna::new("A").unwrap().dinucleotides().next(), None);
+        assert_eq!(Rna::new("").unwrap().dinucleotides().next(), None);
+    }
+
+    #[test]
+    fn test_find_start_codon() {
+        assert_eq!(Rna::new("CCAUGAUG").unwrap().find_start_codon(), Some(2));
+        assert_eq!(Rna::new("AUG").unwrap().find_start_codon(), Some(0));
+        assert_eq!(Rna::new("CCAUAGU").unwrap().find_start_codon(), None);
+        assert_eq!(Rna::new("").unwrap().find_start_codon(), None);
+    }
+
+    #[test]
+    fn test_protein_string() {
+        assert_eq!(
+            Rna::new("AUGUUUUAA").unwrap().protein_string(),
+            Ok("MF".to_owned())
+        );
+        assert_eq!(
+            Rna::new("AUGUUU").unwrap().protein_string(),
+            Ok("MF".to_owned())
+        );
+        assert_eq!(
+            Rna::new("UAGUU").unwrap().protein_string(),
+            Ok("".to_owned())
+        );
+        assert_eq!(
+            Rna::new("AUGUU").unwrap().protein_string(),
+            Err(NucleotideError::OutOfRange(3))
+        );
+        assert_eq!(
+            Rna::new("AUGUUUA").unwrap().protein_string(),
+            Err(NucleotideError::OutOfRange(6))
+        );
+    }
+
+    #[test]
+    fn test_is_valid_orf() {
+        assert!(Rna::new("AUGUUUUAA").unwrap().is_valid_orf());
+        assert!(Rna::new("AUGUGA").unwrap().is_valid_orf());
+        // Internal stop codon.
+        assert!(!Rna::new("AUGUAGUUUUAA").unwrap().is_valid_orf());
+        // Length not divisible by 3.
+        assert!(!Rna::new("AUGUUUUAAU").unwrap().is_valid_orf());
+        // No start, or no stop codon.
+        assert!(!Rna::new("UUUUUUUAA").unwrap().is_valid_orf());
+        assert!(!Rna::new("AUGUUUUUU").unwrap().is_valid_orf());
+        assert!(!Rna::new("AUG").unwrap().is_valid_orf());
+        assert!(!Rna::new("").unwrap().is_valid_orf());
+    }
+
+    #[test]
+    fn test_longest_orf_protein() {
+        // Frame 0: AUG AAA UAA (short ORF "MK"). Frame 1: AUG UUU GGG CCC UGA (longer ORF "MFGP").
+        let rna = Rna::new("AUGAAAUAACAUGUUUGGGCCCUGA").unwrap();
+        assert_eq!(rna.longest_orf_protein().unwrap().as_str(), "MFGP");
+
+        assert_eq!(
+            Rna::new("AUGUAA").unwrap().longest_orf_protein().unwrap(),
+            Protein("M".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_longest_orf_protein_none() {
+        // No start codon.
+        assert_eq!(Rna::new("CCCUAA").unwrap().longest_orf_protein(), None);
+        // No stop codon after the start codon.
+        assert_eq!(Rna::new("AUGCCC").unwrap().longest_orf_protein(), None);
+        assert_eq!(Rna::new("").unwrap().longest_orf_protein(), None);
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn test_global_align() {
+        let left = Rna::new("GAUUACA").unwrap();
+        let right = Rna::new("GCAUGCU").unwrap();
+        let mut scratch = [0i32; 8 * 8];
+        let (left_aligned, right_aligned, score) =
+            left.global_align::<14>(&right, -1, &mut scratch);
+        assert_eq!(score, 0);
+
+        assert_eq!(left_aligned.len(), right_aligned.len());
+        assert_eq!(left_aligned.replace('-', ""), "GAUUACA");
+        assert_eq!(right_aligned.replace('-', ""), "GCAUGCU");
+        // The returned score matches the returned alignment.
+        let alignment_score: i32 = left_aligned
+            .chars()
+            .zip(right_aligned.chars())
+            .map(|pair| match pair {
+                ('-', _) | (_, '-') => -1,
+                (l, r) if l == r => 1,
+                _ => -1,
+            })
+            .sum();
+        assert_eq!(alignment_score, score);
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn test_global_align_identical_and_empty() {
+        let rna = Rna::new("CGAU").unwrap();
+        let mut scratch = [0i32; 25];
+        let (left_aligned, right_aligned, score) = rna.global_align::<8>(&rna, -2, &mut scratch);
+        assert_eq!(
+            (left_aligned.as_str(), right_aligned.as_str(), score),
+            ("CGAU", "CGAU", 4)
+        );
+
+        let empty = Rna::new("").unwrap();
+        let (left_aligned, right_aligned, score) = rna.global_align::<8>(&empty, -2, &mut scratch);
+        assert_eq!(
+            (left_aligned.as_str(), right_aligned.as_str(), score),
+            ("CGAU", "----", -8)
+        );
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn test_sanitize() {
+        let (rna, removed) = Rna::sanitize("CXGAU");
+        assert_eq!(rna, "CGAU");
+        assert_eq!(removed, [1]);
+
+        let (rna, removed) = Rna::sanitize("ACGU");
+        assert_eq!(rna, "ACGU");
+        assert!(removed.is_empty());
+
+        let (rna, removed) = Rna::sanitize(&"X".repeat(20));
+        assert_eq!(rna, "");
+        assert_eq!(removed.len(), 16);
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn test_kmer_counts_into() {
+        let rna = Rna::new("ACACAC").unwrap();
+        let mut map = heapless::FnvIndexMap::<heapless::String<2>, usize, 4>::new();
+        rna.kmer_counts_into(2, &mut map).unwrap();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[&heapless::String::try_from("AC").unwrap()], 3);
+        assert_eq!(map[&heapless::String::try_from("CA").unwrap()], 2);
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn test_kmer_counts_into_full() {
+        let rna = Rna::new("ACGUA").unwrap();
+        let mut map = heapless::FnvIndexMap::<heapless::String<2>, usize, 2>::new();
+        assert_eq!(
+            rna.kmer_counts_into(2, &mut map),
+            Err(NucleotideError::Overflow(2))
+        );
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn test_codons_indexed() {
+        let rna = Rna::new("AUGUUU").unwrap();
+        assert_eq!(
+            rna.codons_indexed().collect::<Vec<_>>(),
+            [(0, ['A', 'U', 'G']), (3, ['U', 'U', 'U'])]
+        );
+        let rna = Rna::new("AUGUU").unwrap();
+        assert_eq!(
+            rna.codons_indexed().collect::<Vec<_>>(),
+            [(0, ['A', 'U', 'G'])]
+        );
+    }
+
+    #[test]
+    fn test_codon_usage() {
+        let usage = Rna::new("AUGUUUAUGGGGAU").unwrap().codon_usage();
+        assert_eq!(usage[35], 2); // AUG
+        assert_eq!(usage[0], 1); // UUU
+        assert_eq!(usage[63], 1); // GGG
+        assert_eq!(usage.iter().sum::<usize>(), 4);
+    }
+
+    #[test]
+    fn test_gc_content() {
+        assert_eq!(Rna::new("GCGC").unwrap().gc_content(), 1.0);
+        assert_eq!(Rna::new("GAUC").unwrap().gc_content(), 0.5);
+        assert_eq!(Rna::new("AUAU").unwrap().gc_content(), 0.0);
+        assert_eq!(Rna::new("").unwrap().gc_content(), 0.0);
+    }
+
+    #[test]
+    fn test_counts() {
+        let counts = Rna::new("AUGGCCCU").unwrap().counts();
+        assert_eq!((counts.a, counts.c, counts.g, counts.u), (1, 3, 2, 2));
+    }
+
+    #[test]
+    fn test_nucleotide_frequencies() {
+        assert_eq!(
+            Rna::new("ACGU").unwrap().nucleotide_frequencies(),
+            [0.25; 4]
+        );
+        assert_eq!(
+            Rna::new("AAAA").unwrap().nucleotide_frequencies(),
+            [1.0, 0.0, 0.0, 0.0]
+        );
+        assert_eq!(Rna::new("").unwrap().nucleotide_frequencies(), [0.0; 4]);
+    }
+
+    #[test]
+    fn test_iupac_n() {
+        // Only with feature `utils/iupac`. Otherwise N is invalid.
+        let (Ok(dna), Ok(rna)) = (Dna::new("ACGN"), Rna::new("AUGNCCUAA")) else {
+            return;
+        };
+        assert_eq!(
+            dna.two_bit_encode(&mut [0u8; 1]),
+            Err(NucleotideError::Invalid(3))
+        );
+        assert_eq!(rna.pack(), Err(NucleotideError::Invalid(3)));
+        assert_eq!(rna.bases().nth(3).map(char::from), Some('N'));
+        assert_eq!(rna.counts().a, 3);
+        assert_eq!(rna.nucleotide_frequencies()[0], 3.0 / 9.0);
+        assert_eq!(rna.codon_usage().iter().sum::<usize>(), 2);
+        assert_eq!(rna.protein_string(), Ok("MX".to_owned()));
+        assert_eq!(rna.longest_orf_protein(), Some(Protein("MX".to_owned())));
+    }
+
+    #[test]
+    fn test_gc_count_sum() {
+        let rnas = [
+            Rna::new("GCGC").unwrap(),
+            Rna::new("AU").unwrap(),
+            Rna::new("GAUCAU").unwrap(),
+        ];
+        let total: GcCount = rnas.iter().map(|r| r.gc_count()).sum();
+        assert_eq!(total, GcCount { gc: 6, total: 12 });
+        assert_eq!(total.ratio(), 0.5);
+        assert_eq!(GcCount::default().ratio(), 0.0);
+    }
+
+    #[test]
+    fn test_gc_percent() {
+        assert_eq!(Rna::new("GCGC").unwrap().gc_percent(), 100);
+        assert_eq!(Rna::new("GAUC").unwrap().gc_percent(), 50);
+        assert_eq!(Rna::new("GAU").unwrap().gc_percent(), 33);
+        assert_eq!(Rna::new("GCU").unwrap().gc_percent(), 67);
+        assert_eq!(Rna::new("").unwrap().gc_percent(), 0);
+    }
+
+    #[test]
+    fn test_contains() {
+        let rna = Rna::new("AUGCCGUAA").unwrap();
+        assert!(rna.contains("CCG"));
+        assert!(rna.contains("AUGCCGUAA"));
+        assert!(!rna.contains("CCA"));
+        assert!(!rna.contains("AUGCCGUAAA"));
+        assert!(rna.contains(""));
+    }
+
+    #[test]
+    fn test_is_reverse_of() {
+        let rna = Rna::new("CGAU").unwrap();
+        assert!(rna.is_reverse_of(&Rna::new("UAGC").unwrap()));
+        assert!(!rna.is_reverse_of(&rna));
+        assert!(!rna.is_reverse_of(&Rna::new("UAG").unwrap()));
+        let palindrome = Rna::new("CGAGC").unwrap();
+        assert!(palindrome.is_reverse_of(&palindrome));
+    }
+
+    #[test]
+    fn test_is_subsequence_of() {
+        let rna = Rna::new("AGC").unwrap();
+        assert!(rna.is_subsequence_of("AAGGCC"));
+        assert!(rna.is_subsequence_of("AGC"));
+        assert!(!rna.is_subsequence_of("CGA"));
+        assert!(!rna.is_subsequence_of("AG"));
+        assert!(Rna::new("").unwrap().is_subsequence_of(""));
+    }
+
+    #[test]
+    fn test_trim_matches() {
+        let rna = Rna::new("AACGUAA").unwrap();
+        assert_eq!(rna.trim_matches('A'), Rna::new("CGU").unwrap());
+        assert_eq!(rna.trim_matches('G'), rna);
+        assert_eq!(
+            Rna::new("AAAA").unwrap().trim_matches('A'),
+            Rna::new("").unwrap()
+        );
+    }
+}
@@ -8,6 +8,15 @@ use utils::{checks, DnaTrait, OurResult, RnaTrait};
 /// This is higher than `32`, so that we make sure to implement [`Default`] ourselves. ([`Default`]
 /// can be derived for arrays only up to size `32`.)
 const MAX_NUM_RNA_NUCLEOTIDES: usize = 40;
+// Keep it above `32`, so that the storage keeps exercising our own [`default_chars`] rather than
+// the derived array [`Default`].
+const _: () = assert!(MAX_NUM_RNA_NUCLEOTIDES > 32);
+
+/// Storage of `N` default (`'\0'`) characters. Unlike [`Default`] for arrays, this works for any
+/// `N`.
+fn default_chars<const N: usize>() -> [char; N] {
+    core::array::from_fn(|_| char::default())
+}
 
 // @TODO Others: Derive/impl Clone.
 
@@ -29,6 +38,10 @@ impl<'a> DnaTrait<'a, Rna> for Dna<'a> {
         Ok(Self(dna))
     }
 
+    fn as_str(&self) -> &str {
+        self.0
+    }
+
     fn into_rna(&self) -> Rna {
         Rna::new_from_iter(self.0.chars().map(utils::dna_to_rna)).expect("RNA")
     }
@@ -57,12 +70,27 @@ impl Rna {
     fn chars(&self) -> &[char] {
         &self.rna[..self.len]
     }
+
+    /// Number of nucleotides (not the storage capacity).
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether there are no nucleotides.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of occurrences of nucleotide `c`.
+    pub fn count(&self, c: char) -> usize {
+        self.chars().iter().filter(|&&nucl| nucl == c).count()
+    }
 }
 
 impl Default for Rna {
     fn default() -> Self {
         Self {
-            rna: [char::default(); MAX_NUM_RNA_NUCLEOTIDES],
+            rna: default_chars(),
             len: 0,
         }
     }
@@ -94,3 +122,36 @@ impl Debug for Rna {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{default_chars, Rna};
+    use utils::RnaTrait;
+
+    /// Like [`Rna`], but with storage size given by a const generic parameter.
+    struct Chars<const N: usize>([char; N]);
+
+    impl<const N: usize> Default for Chars<N> {
+        fn default() -> Self {
+            Self(default_chars())
+        }
+    }
+
+    #[test]
+    fn test_default_above_32() {
+        let chars = Chars::<64>::default();
+        assert!(chars.0.iter().all(|&c| c == char::default()));
+
+        let rna = Rna::default();
+        assert_eq!(rna.len, 0);
+        assert!(rna.rna.iter().all(|&c| c == char::default()));
+    }
+
+    #[test]
+    fn test_count() {
+        let rna = Rna::new("CGAUA").unwrap();
+        assert_eq!(rna.count('A'), 2);
+        assert_eq!(rna.count('U'), 1);
+        assert_eq!(rna.count('T'), 0);
+    }
+}
@@ -1,8 +1,8 @@
 #![no_std]
-#![feature(generic_associated_types)]
 
 use ret_tok_rnd_modify_any_store_own_arr_const_overall_limit_chars as dna;
 use test_harness::api_tests_read_only::Tests;
+use utils::RnaTrait;
 
 struct T {}
 impl Tests for T {
@@ -14,3 +14,14 @@ impl Tests for T {
 fn all_tests() {
     T::all_tests();
 }
+
+#[test]
+fn test_rna_len() {
+    let rna = dna::Rna::new("CGAU").unwrap();
+    assert_eq!(rna.len(), 4);
+    assert!(!rna.is_empty());
+
+    let rna = dna::Rna::new("").unwrap();
+    assert_eq!(rna.len(), 0);
+    assert!(rna.is_empty());
+}
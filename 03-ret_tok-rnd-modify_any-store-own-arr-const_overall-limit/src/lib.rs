@@ -1,12 +1,9 @@
 //! no_std heapless (bare metal/embedded-friendly)
 #![no_std]
-// generic_associated_types are not required for the implementation itself, but only for
-// `api_tests_mut_wipe_on_clone` unit tests.
-// #[cfg_attr(test, feature = "generic_associated_types" )]
-#![cfg_attr(test, feature(generic_associated_types))]
 
 use core::fmt::{self, Debug, Formatter};
-use core::str;
+use core::hash::{Hash, Hasher};
+use core::str::{self, Utf8Error};
 use utils::api_tests_mut::RnaTraitMutLeakStorage;
 use utils::{checks, DnaTrait, OurResult, RnaTrait, RnaTraitMut};
 
@@ -31,8 +28,14 @@ impl<'a> DnaTrait<'a, Rna> for Dna<'a> {
         Ok(Self(dna))
     }
 
+    fn as_str(&self) -> &str {
+        self.0
+    }
+
     fn into_rna(&self) -> Rna {
-        Rna::new_from_iter(self.0.chars().map(utils::dna_to_rna)).expect("RNA")
+        let mut result = Rna::default();
+        result.len = utils::transcribe_slice(self.0.as_bytes(), &mut result.rna).expect("RNA");
+        result
     }
 }
 
@@ -65,7 +68,63 @@ impl Rna {
     }
 
     fn as_str(&self) -> &str {
-        str::from_utf8(&self.rna[..self.len]).expect("UTF-8 encoded string of RNA nucleotides")
+        self.try_as_str()
+            .expect("UTF-8 encoded string of RNA nucleotides")
+    }
+
+    /// Like `as_str`, but return [`Err`] (rather than panic) if the used bytes are not valid UTF-8.
+    /// That should never happen, but this can't panic (for example, when debugging).
+    pub fn try_as_str(&self) -> Result<&str, Utf8Error> {
+        str::from_utf8(&self.rna[..self.len])
+    }
+
+    /// Number of occurrences of nucleotide `c`.
+    pub fn count(&self, c: char) -> usize {
+        self.rna[..self.len]
+            .iter()
+            .filter(|&&nucl| char::from(nucl) == c)
+            .count()
+    }
+
+    /// Number of occurrences of each nucleotide, in order A, C, G, U. Any N (any nucleotide, with
+    /// feature `utils/iupac`) isn't counted.
+    pub fn base_histogram(&self) -> [usize; 4] {
+        let mut histogram = [0; 4];
+        for &nucl in &self.rna[..self.len] {
+            let i = match nucl {
+                b'A' => 0,
+                b'C' => 1,
+                b'G' => 2,
+                b'U' => 3,
+                b'N' => continue,
+                _ => unreachable!("RNA nucleotide"),
+            };
+            histogram[i] += 1;
+        }
+        histogram
+    }
+
+    /// Number of positions where `self` and `other` have different nucleotides (Hamming distance).
+    /// Return [`None`] if their lengths differ.
+    pub fn count_differences(&self, other: &Rna) -> Option<usize> {
+        if self.len != other.len {
+            return None;
+        }
+        let differences = self.rna[..self.len]
+            .iter()
+            .zip(&other.rna[..other.len])
+            .filter(|(left, right)| left != right)
+            .count();
+        Some(differences)
+    }
+
+    /// Inject bytes without any validation, so we can test handling of invalid data.
+    #[cfg(test)]
+    fn new_unchecked_bytes(bytes: &[u8]) -> Self {
+        let mut result = Rna::default();
+        result.rna[..bytes.len()].copy_from_slice(bytes);
+        result.len = bytes.len();
+        result
     }
 }
 
@@ -75,6 +134,13 @@ impl<'a> RnaTraitMut<'a> for Rna {
         #[allow(clippy::map_identity)]
         self.set_from_iter_impl(iter.map(core::convert::identity))
     }
+
+    fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len {
+            self.rna[new_len..self.len].fill(u8::default());
+            self.len = new_len;
+        }
+    }
 }
 
 impl<'a> RnaTraitMutLeakStorage<'a> for Rna {}
@@ -97,8 +163,145 @@ impl PartialEq for Rna {
 }
 impl Eq for Rna {}
 
+/// Consistent with [`PartialEq`]: it hashes only the used bytes, never the leftover storage.
+impl Hash for Rna {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rna[..self.len].hash(state);
+    }
+}
+
+impl PartialEq<str> for Rna {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+impl PartialEq<&str> for Rna {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<Rna> for str {
+    fn eq(&self, other: &Rna) -> bool {
+        other == self
+    }
+}
+impl PartialEq<Rna> for &str {
+    fn eq(&self, other: &Rna) -> bool {
+        other == self
+    }
+}
+
+/// If the used bytes are not valid UTF-8 (which should never happen), show them as a hex dump
+/// instead of panicking.
 impl Debug for Rna {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
-        write!(f, "Rna(\"{}\")", self.as_str())
+        match self.try_as_str() {
+            Ok(rna) => write!(f, "Rna(\"{rna}\")"),
+            Err(_) => {
+                write!(f, "Rna(0x")?;
+                for b in &self.rna[..self.len] {
+                    write!(f, "{b:02x}")?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::{Dna, Rna};
+    use alloc::format;
+    use core::hash::{Hash, Hasher};
+    use utils::{DnaTrait, RnaTrait};
+
+    /// FNV-1a, as a minimal no_std [`Hasher`] for tests.
+    struct FnvHasher(u64);
+
+    impl Hasher for FnvHasher {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for &b in bytes {
+                self.0 = (self.0 ^ u64::from(b)).wrapping_mul(0x100_0000_01b3);
+            }
+        }
+    }
+
+    fn hash_of(rna: &Rna) -> u64 {
+        let mut hasher = FnvHasher(0xcbf2_9ce4_8422_2325);
+        rna.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_hash_matches_eq() {
+        let given = Rna::new("CGAU").unwrap();
+        let transcribed = Dna::new("GCTA").unwrap().into_rna();
+        // Equal, even with different leftover bytes.
+        let mut leftover = Rna::new("CGAU").unwrap();
+        leftover.rna[10] = b'G';
+        assert_eq!(given, transcribed);
+        assert_eq!(given, leftover);
+        assert_eq!(hash_of(&given), hash_of(&transcribed));
+        assert_eq!(hash_of(&given), hash_of(&leftover));
+
+        assert_ne!(hash_of(&given), hash_of(&Rna::new("CGAA").unwrap()));
+    }
+
+    #[test]
+    fn test_eq_str() {
+        let rna = Rna::new("CGAU").unwrap();
+        assert_eq!(rna, "CGAU");
+        assert_eq!("CGAU", rna);
+        assert_eq!(*"CGAU", rna);
+        assert_ne!("CGA", rna);
+    }
+
+    #[test]
+    fn test_try_as_str() {
+        let rna = Rna::new("CGAU").unwrap();
+        assert_eq!(rna.try_as_str(), Ok("CGAU"));
+        assert!(Rna::new_unchecked_bytes(&[b'C', 0xFF])
+            .try_as_str()
+            .is_err());
+    }
+
+    #[test]
+    fn test_count() {
+        let rna = Rna::new("CGAUA").unwrap();
+        assert_eq!(rna.count('A'), 2);
+        assert_eq!(rna.count('U'), 1);
+        assert_eq!(rna.count('T'), 0);
+    }
+
+    #[test]
+    fn test_base_histogram() {
+        assert_eq!(Rna::new("AACG").unwrap().base_histogram(), [2, 1, 1, 0]);
+        assert_eq!(Rna::new("").unwrap().base_histogram(), [0; 4]);
+        // Only with feature `utils/iupac`. Otherwise N is invalid.
+        if let Ok(rna) = Rna::new("ANCN") {
+            assert_eq!(rna.base_histogram(), [1, 1, 0, 0]);
+        }
+    }
+
+    #[test]
+    fn test_count_differences() {
+        let rna = Rna::new("CGAU").unwrap();
+        assert_eq!(rna.count_differences(&Rna::new("CGAU").unwrap()), Some(0));
+        assert_eq!(rna.count_differences(&Rna::new("CUAA").unwrap()), Some(2));
+        assert_eq!(rna.count_differences(&Rna::new("CGA").unwrap()), None);
+    }
+
+    #[test]
+    fn test_debug() {
+        let rna = Rna::new("CGAU").unwrap();
+        assert_eq!(format!("{rna:?}"), "Rna(\"CGAU\")");
+        let invalid = Rna::new_unchecked_bytes(&[b'C', 0xFF]);
+        assert_eq!(format!("{invalid:?}"), "Rna(0x43ff)");
     }
 }
@@ -1,5 +1,4 @@
 #![no_std]
-#![feature(generic_associated_types)]
 
 use ret_tok_rnd_modify_any_store_own_arr_const_overall_limit as dna;
 
@@ -1,5 +1,5 @@
-///! Security unit test. This can't be an integration test (under ../tests/), because it needs
-///! private access to [`dna::Rna::rna`].
+//! Security unit test. This can't be an integration test (under ../tests/), because it needs
+//! private access to [`dna::Rna::rna`].
 use crate as dna;
 
 use test_harness::api_tests_mut::wipe_on_mut::Tests;
@@ -11,19 +11,19 @@ impl Tests for T {
     type Rna<'a> = dna::Rna;
 }
 
-fn with_storage_leaked<RES>(
+fn with_storage_leaked(
     rna: &dna::Rna,
-    with_storage_leaked_call_back: WithStorageLeakedCallBack<bool>,
+    with_storage_leaked_call_back: WithStorageLeakedCallBack<'_, bool>,
 ) -> bool {
     let bytes = &rna.rna[..];
-    let mut bytes_iter = bytes.into_iter().cloned();
+    let mut bytes_iter = bytes.iter().cloned();
     with_storage_leaked_call_back(&mut bytes_iter)
 }
 type _TWithStorageLeaked<'a> = WithStorageLeaked<'a, dna::Rna, bool>;
-const _CHECK_WITH_STORAGE_LEAKED_FUNCTION_SIGNATURE: _TWithStorageLeaked =
-    &with_storage_leaked::<bool>;
+const _CHECK_WITH_STORAGE_LEAKED_FUNCTION_SIGNATURE: _TWithStorageLeaked = &with_storage_leaked;
 
 #[test]
 fn all_tests() {
-    T::test_modify_string_based_rna_mutation_does_not_leak(&with_storage_leaked::<bool>);
+    T::test_modify_string_based_rna_mutation_does_not_leak(&with_storage_leaked);
+    T::test_truncate_does_not_leak(&with_storage_leaked);
 }
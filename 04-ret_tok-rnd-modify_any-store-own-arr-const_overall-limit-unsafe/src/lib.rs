@@ -1,9 +1,5 @@
 //! no_std heapless (bare metal/embedded-friendly)
 #![no_std]
-// Generic_associated_types are not required for the implementation itself, but only for
-// `api_tests_mut_wipe_on_clone` unit tests.
-// #![cfg_attr(test, feature = "generic_associated_types" )]
-#![cfg_attr(test, feature(generic_associated_types))]
 
 use core::fmt::{self, Debug, Formatter};
 use core::{slice, str};
@@ -30,6 +26,10 @@ impl<'a> DnaTrait<'a, Rna> for Dna<'a> {
         Ok(Self(dna))
     }
 
+    fn as_str(&self) -> &str {
+        self.0
+    }
+
     fn into_rna(&self) -> Rna {
         Rna::new_from_iter(self.0.chars().map(utils::dna_to_rna)).expect("RNA")
     }
@@ -79,6 +79,13 @@ impl<'a> RnaTraitMut<'a> for Rna {
     fn set_from_iter(&mut self, iter: &mut dyn Iterator<Item = char>) -> OurResult<()> {
         self.set_from_iter_impl(iter)
     }
+
+    fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len {
+            self.rna[new_len..self.len].fill(u8::default());
+            self.len = new_len;
+        }
+    }
 }
 
 impl<'a> RnaTraitMutLeakStorage<'a> for Rna {}
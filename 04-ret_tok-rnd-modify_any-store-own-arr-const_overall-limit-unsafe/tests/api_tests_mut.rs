@@ -0,0 +1,17 @@
+#![no_std]
+
+#[allow(clippy::unsafe_removed_from_name)]
+use ret_tok_rnd_modify_any_store_own_arr_const_overall_limit_unsafe as dna;
+
+use test_harness::api_tests_mut::Tests;
+
+struct T {}
+impl Tests for T {
+    type Dna<'a> = dna::Dna<'a>;
+    type Rna<'a> = dna::Rna;
+}
+
+#[test]
+fn all_tests() {
+    T::all_tests().expect("api_tests_mut");
+}
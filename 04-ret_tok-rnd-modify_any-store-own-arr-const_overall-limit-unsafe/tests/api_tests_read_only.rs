@@ -1,6 +1,6 @@
 #![no_std]
-#![feature(generic_associated_types)]
 
+#[allow(clippy::unsafe_removed_from_name)]
 use ret_tok_rnd_modify_any_store_own_arr_const_overall_limit_unsafe as dna;
 
 use test_harness::api_tests_read_only::Tests;
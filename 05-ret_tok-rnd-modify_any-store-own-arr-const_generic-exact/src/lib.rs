@@ -44,6 +44,10 @@ impl<'a, const N: usize> DnaTrait<'a, Rna<N>> for Dna<'a, N> {
         Ok(Self(dna))
     }
 
+    fn as_str(&self) -> &str {
+        self.0
+    }
+
     /// Create an [`Rna`] instance, based on `self`. The returned instance contains the translated
     /// nucleotides. (The result doesn't depend on the original [`Dna`] instance's lifetime). TODO
     /// add similar doc to `ok_heap_string`.
@@ -17,21 +17,27 @@ fn test_valid_rna_input() {
 #[test]
 fn test_invalid_dna_input() {
     // Invalid character
-    assert_eq!(<dna::Dna<0>>::new("X").err(), Some(0));
+    assert_eq!(<dna::Dna<0>>::new("X").err().map(usize::from), Some(0));
     // Valid nucleotide, but invalid in context
-    assert_eq!(<dna::Dna<0>>::new("U").err(), Some(0));
+    assert_eq!(<dna::Dna<0>>::new("U").err().map(usize::from), Some(0));
     // Longer string with contained errors
-    assert_eq!(<dna::Dna<0>>::new("ACGTUXXCTTAA").err(), Some(4));
+    assert_eq!(
+        <dna::Dna<0>>::new("ACGTUXXCTTAA").err().map(usize::from),
+        Some(4)
+    );
 }
 
 #[test]
 fn test_invalid_rna_input() {
     // Invalid character
-    assert_eq!(<dna::Rna<1>>::new("X").unwrap_err(), 0);
+    assert_eq!(usize::from(<dna::Rna<1>>::new("X").unwrap_err()), 0);
     // Valid nucleotide, but invalid in context
-    assert_eq!(<dna::Rna<1>>::new("T").unwrap_err(), 0);
+    assert_eq!(usize::from(<dna::Rna<1>>::new("T").unwrap_err()), 0);
     // Longer string with contained errors
-    assert_eq!(<dna::Rna<12>>::new("ACGUTTXCUUAA").unwrap_err(), 4);
+    assert_eq!(
+        usize::from(<dna::Rna<12>>::new("ACGUTTXCUUAA").unwrap_err()),
+        4
+    );
 }
 
 #[test]
@@ -1,9 +1,16 @@
 //! no_std heapless (bare metal/embedded-friendly)
 #![no_std]
 
-use core::fmt::{self, Debug, Formatter};
-use core::str;
-use utils::{checks, DnaTrait, OurResult, RnaTrait};
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Formatter, Write};
+use core::iter::Copied;
+use core::slice;
+use core::str::{self, FromStr};
+use utils::api_tests_mut::RnaTraitMutLeakStorage;
+use utils::{checks, DnaTrait, Nucleotide, NucleotideError, OurResult, RnaTrait, RnaTraitMut};
+
+#[cfg(test)]
+mod api_tests_mut_wipe_on_mut;
 
 const DEFAULT_MAX_NUCLEOTIDES: usize = 12;
 
@@ -34,6 +41,25 @@ pub struct RnaImpl<const M: usize = DEFAULT_MAX_NUCLEOTIDES> {
 
 pub type Rna = RnaImpl<DEFAULT_MAX_NUCLEOTIDES>;
 
+/// Used by [`const_dna`]. Not a part of the public API.
+#[doc(hidden)]
+pub use utils::DnaTrait as __DnaTrait;
+
+/// Create a [`DnaImpl<M>`](DnaImpl) from a string literal: `const_dna!(M, "ACGT")`. Unlike
+/// [`DnaImpl::new`], this checks at compile time that the literal is not longer than `M`, so the
+/// result's [`DnaTrait::into_rna`] can't panic on overflow. ([`panic`] on invalid nucleotides,
+/// which are still checked at runtime.)
+#[macro_export]
+macro_rules! const_dna {
+    ($m:expr, $dna:literal) => {{
+        const _: () = assert!(
+            $dna.len() <= $m,
+            "const_dna!: the DNA literal is longer than the maximum size M."
+        );
+        <$crate::DnaImpl<{ $m }> as $crate::__DnaTrait<_>>::new($dna).unwrap()
+    }};
+}
+
 impl<'a, const M: usize> DnaTrait<'a, RnaImpl<M>> for DnaImpl<'a, M> {
     /// Create a new [`Dna`] instance with given DNA nucleotides. If `dna` is valid, return  
     /// [`Some(Dna)`](Some<Dna>) containing the new instance. On error return [`Err`] with a 0-based
@@ -43,11 +69,49 @@ impl<'a, const M: usize> DnaTrait<'a, RnaImpl<M>> for DnaImpl<'a, M> {
         Ok(Self(dna))
     }
 
+    fn as_str(&self) -> &str {
+        self.0
+    }
+
     /// Create an [`Rna`] instance, based on `self`. The returned instance contains the translated
     /// nucleotides. (The result doesn't depend on the original [`Dna`] instance's lifetime). TODO
     /// add similar doc to `ok_heap_string`.
     fn into_rna(&self) -> RnaImpl<M> {
-        RnaImpl::new_from_iter(self.0.chars().map(utils::dna_to_rna)).expect("RNA sequence")
+        let mut result = RnaImpl::default();
+        result.len =
+            utils::transcribe_slice(self.0.as_bytes(), &mut result.rna).expect("RNA sequence");
+        result
+    }
+}
+
+impl<'a, const M: usize> DnaImpl<'a, M> {
+    /// Relabel as [`DnaImpl<N>`](DnaImpl) (targeting a different RNA capacity), borrowing the same
+    /// nucleotides. This doesn't check the length, so the result's [`DnaTrait::into_rna`] panics
+    /// if `self` is longer than `N`. See [`DnaImpl::try_with_max`].
+    pub fn with_max<const N: usize>(self) -> DnaImpl<'a, N> {
+        DnaImpl(self.0)
+    }
+
+    /// Like [`DnaImpl::with_max`], but check that `self` fits in `N` (so that the result's
+    /// [`DnaTrait::into_rna`] doesn't panic). On error return [`Err`] with
+    /// [`NucleotideError::Overflow`] with `N` (index of the first nucleotide that doesn't fit).
+    pub fn try_with_max<const N: usize>(self) -> OurResult<DnaImpl<'a, N>> {
+        // DNA nucleotides are ASCII, so there is one byte per nucleotide.
+        if self.0.len() > N {
+            return Err(NucleotideError::Overflow(N));
+        }
+        Ok(self.with_max())
+    }
+
+    /// DNA reverse complement (heapless), as ASCII bytes in an array, and the number of used bytes.
+    /// Any remaining bytes are zero. On error (if `self` is longer than `M`) return [`Err`] with
+    /// [`NucleotideError::Overflow`] with `M`.
+    pub fn reverse_complement(&self) -> OurResult<([u8; M], usize)> {
+        let mut result = [0; M];
+        // `self` was validated, so the only possible error is that `result` is too short.
+        let len = utils::reverse_complement_into(self.0.as_bytes(), &mut result)
+            .map_err(|_| NucleotideError::Overflow(M))?;
+        Ok((result, len))
     }
 }
 
@@ -57,6 +121,48 @@ impl<'a, const M: usize> RnaTrait<'a> for RnaImpl<M> {
     }
 }
 
+impl<'a, const M: usize> RnaTraitMut<'a> for RnaImpl<M> {
+    /// On error leave `self` unchanged. On success, the whole storage is overwritten, so no
+    /// previous data is left over.
+    fn set_from_iter(&mut self, iter: &mut dyn Iterator<Item = char>) -> OurResult<()> {
+        *self = Self::new_from_iter(iter)?;
+        Ok(())
+    }
+
+    fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len {
+            self.rna[new_len..self.len].fill(0);
+            self.len = new_len;
+        }
+    }
+}
+
+impl<'a, const M: usize> RnaTraitMutLeakStorage<'a> for RnaImpl<M> {}
+
+/// Parse RNA nucleotides, as per [`RnaTrait::new`]. On error return [`Err`] with
+/// [`NucleotideError::Invalid`] (a 0-based index of the first incorrect character) - or, if `s` is
+/// longer than `M`, with [`NucleotideError::Overflow`] (`M`, index of the first nucleotide that
+/// doesn't fit).
+impl<const M: usize> FromStr for RnaImpl<M> {
+    type Err = NucleotideError;
+
+    fn from_str(s: &str) -> OurResult<Self> {
+        Self::new(s)
+    }
+}
+
+/// Take a full array of RNA nucleotides (as ASCII bytes), so the result's length is `N`. On error
+/// return [`Err`] with [`NucleotideError::Invalid`] (a 0-based index of the first incorrect byte).
+impl<const N: usize> TryFrom<[u8; N]> for RnaImpl<N> {
+    type Error = NucleotideError;
+
+    fn try_from(rna: [u8; N]) -> OurResult<Self> {
+        // Any non-ASCII byte maps to a non-ASCII char, which is not a nucleotide.
+        checks::check_rna_char_iter(rna.iter().map(|&b| b as char))?;
+        Ok(Self { rna, len: N })
+    }
+}
+
 impl<const M: usize> RnaImpl<M> {
     pub fn new_from_iter(rna_chars_iter: impl Iterator<Item = char>) -> OurResult<Self> {
         let mut len = 0usize;
@@ -71,7 +177,7 @@ impl<const M: usize> RnaImpl<M> {
         });
         if rna_bytes_iter.next().is_some() {
             // Extra bytes left.
-            return Err(len);
+            return Err(NucleotideError::Overflow(len));
         }
         let result = Self { rna, len };
         checks::check_rna_str(result.as_str())?;
@@ -82,6 +188,223 @@ impl<const M: usize> RnaImpl<M> {
         str::from_utf8(&self.rna[..self.len]).expect("UTF-8 encoded string of RNA nucleotides")
     }
 
+    /// Number of used bytes (nucleotides).
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Maximum number of nucleotides (`M`), for generic code that needs to check for room (for
+    /// example, before [`RnaImpl::clone_max_size`]).
+    pub const fn capacity(&self) -> usize {
+        M
+    }
+
+    /// The used bytes (ASCII nucleotides), for byte-level consumers (for example, checksums).
+    pub fn bytes(&self) -> &[u8] {
+        &self.rna[..self.len]
+    }
+
+    /// Copy of the used bytes (ASCII nucleotides) as an array (for example, for a fixed-size
+    /// protocol frame). Return [`None`] unless the length is exactly `N`.
+    pub fn as_ascii_array<const N: usize>(&self) -> Option<[u8; N]> {
+        self.bytes().try_into().ok()
+    }
+
+    /// The nucleotide at 0-based index `i`, typed. Return [`None`] if `i` is not less than
+    /// [`RnaImpl::len`].
+    pub fn base(&self, i: usize) -> Option<Nucleotide> {
+        self.rna[..self.len]
+            .get(i)
+            .map(|&b| Nucleotide::try_from(b as char).expect("RNA nucleotide"))
+    }
+
+    /// Iterate over the nucleotides back-to-front. This doesn't allocate.
+    pub fn iter_rev(&self) -> impl Iterator<Item = char> + '_ {
+        self.rna[..self.len].iter().rev().map(|&b| b as char)
+    }
+
+    /// Replace nucleotides in range `start..end` with `replacement`, shifting any following
+    /// nucleotides as needed. Any storage freed by shortening is wiped (zeroed). On error leave
+    /// `self` unchanged and return [`Err`] with:
+    /// - [`NucleotideError::OutOfRange`] with `end` if `end` is past the end of `self`, or with
+    ///   `start` if `start > end`,
+    /// - [`NucleotideError::Invalid`] with a 0-based index (within `replacement`) of the first
+    ///   incorrect character, or
+    /// - [`NucleotideError::Overflow`] with `M` if the result would not fit (index of the first
+    ///   nucleotide that doesn't fit).
+    pub fn splice(&mut self, start: usize, end: usize, replacement: &str) -> OurResult<()> {
+        if end > self.len {
+            return Err(NucleotideError::OutOfRange(end));
+        }
+        if start > end {
+            return Err(NucleotideError::OutOfRange(start));
+        }
+        checks::check_rna_str(replacement)?;
+        // RNA nucleotides are ASCII, so there is one byte per nucleotide.
+        let new_len = self.len - (end - start) + replacement.len();
+        if new_len > M {
+            return Err(NucleotideError::Overflow(M));
+        }
+        let replacement_end = start + replacement.len();
+        self.rna.copy_within(end..self.len, replacement_end);
+        self.rna[start..replacement_end].copy_from_slice(replacement.as_bytes());
+        if new_len < self.len {
+            self.rna[new_len..self.len].fill(0);
+        }
+        self.len = new_len;
+        Ok(())
+    }
+
+    /// Number of occurrences of nucleotide `c`.
+    pub fn count(&self, c: char) -> usize {
+        self.rna[..self.len]
+            .iter()
+            .filter(|&&nucl| char::from(nucl) == c)
+            .count()
+    }
+
+    /// Number of occurrences of each nucleotide, in order A, C, G, U. Any N (any nucleotide, with
+    /// feature `utils/iupac`) isn't counted.
+    pub fn base_histogram(&self) -> [usize; 4] {
+        let mut histogram = [0; 4];
+        for &nucl in &self.rna[..self.len] {
+            let i = match nucl {
+                b'A' => 0,
+                b'C' => 1,
+                b'G' => 2,
+                b'U' => 3,
+                b'N' => continue,
+                _ => unreachable!("RNA nucleotide"),
+            };
+            histogram[i] += 1;
+        }
+        histogram
+    }
+
+    /// Number of positions where `self` and `other` have different nucleotides (Hamming distance).
+    /// Return [`None`] if their lengths differ. Their maximum sizes may differ.
+    pub fn count_differences<const N: usize>(&self, other: &RnaImpl<N>) -> Option<usize> {
+        if self.len != other.len {
+            return None;
+        }
+        let differences = self.rna[..self.len]
+            .iter()
+            .zip(&other.rna[..other.len])
+            .filter(|(left, right)| left != right)
+            .count();
+        Some(differences)
+    }
+
+    /// Replace each nucleotide with its complement (A <-> U, G <-> C, and N <-> N with feature
+    /// `utils/iupac`). The length is unchanged, so there is nothing to wipe.
+    pub fn complement_in_place(&mut self) {
+        for b in &mut self.rna[..self.len] {
+            *b = utils::rna_complement(*b).expect("RNA nucleotide");
+        }
+    }
+
+    /// Replace `self` with its reverse complement (for example, for antisense RNA). The length is
+    /// unchanged, so there is nothing to wipe.
+    pub fn reverse_complement_in_place(&mut self) {
+        self.complement_in_place();
+        self.rna[..self.len].reverse();
+    }
+
+    /// Append a codon (for example, when building a synthetic gene codon by codon). On error leave
+    /// `self` unchanged and return [`Err`] with:
+    /// - [`NucleotideError::Invalid`] with a 0-based index (within `codon`) of the first incorrect
+    ///   character, or
+    /// - [`NucleotideError::Overflow`] with `M` if the result would not fit (index of the first
+    ///   nucleotide that doesn't fit).
+    pub fn push_codon(&mut self, codon: [char; 3]) -> OurResult<()> {
+        checks::check_rna_chars(&codon)?;
+        if self.len + codon.len() > M {
+            return Err(NucleotideError::Overflow(M));
+        }
+        for (b, c) in self.rna[self.len..].iter_mut().zip(codon) {
+            *b = c as u8;
+        }
+        self.len += codon.len();
+        Ok(())
+    }
+
+    /// Insert nucleotide `base` at `index`, shifting any following nucleotides to the right. On
+    /// error leave `self` unchanged and return [`Err`] with:
+    /// - [`NucleotideError::OutOfRange`] with `index` if `index` is past the end of `self`,
+    /// - [`NucleotideError::Invalid`] with `0` if `base` is not an RNA nucleotide, or
+    /// - [`NucleotideError::Overflow`] with `M` if `self` is full.
+    pub fn insert(&mut self, index: usize, base: char) -> OurResult<()> {
+        if index > self.len {
+            return Err(NucleotideError::OutOfRange(index));
+        }
+        checks::check_rna_chars(&[base])?;
+        if self.len == M {
+            return Err(NucleotideError::Overflow(M));
+        }
+        self.rna.copy_within(index..self.len, index + 1);
+        self.rna[index] = base as u8;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Remove and return the nucleotide at `index`, shifting any following nucleotides to the
+    /// left. The freed storage is wiped (zeroed). On error (if `index` is not less than the length)
+    /// leave `self` unchanged and return [`Err`] with [`NucleotideError::OutOfRange`] with `index`.
+    pub fn remove(&mut self, index: usize) -> OurResult<char> {
+        if index >= self.len {
+            return Err(NucleotideError::OutOfRange(index));
+        }
+        let removed = self.rna[index] as char;
+        self.rna.copy_within(index + 1..self.len, index);
+        self.len -= 1;
+        self.rna[self.len] = 0;
+        Ok(removed)
+    }
+
+    /// A [`Write`] sink appending to `self`, so that `write!` can be used. See [`RnaWriter`].
+    pub fn writer(&mut self) -> RnaWriter<'_, M> {
+        RnaWriter(self)
+    }
+
+    /// Concatenate `self` and `other` into a new [`RnaImpl<OUT>`](RnaImpl). On error (if the result
+    /// would not fit) return [`Err`] with [`NucleotideError::Overflow`] carrying the length the
+    /// result would need.
+    pub fn try_concat<const R: usize, const OUT: usize>(
+        &self,
+        other: &RnaImpl<R>,
+    ) -> OurResult<RnaImpl<OUT>> {
+        let len = self.len + other.len;
+        if len > OUT {
+            return Err(NucleotideError::Overflow(len));
+        }
+        let mut result = RnaImpl::<OUT>::default();
+        result.rna[..self.len].copy_from_slice(self.bytes());
+        result.rna[self.len..len].copy_from_slice(other.bytes());
+        result.len = len;
+        Ok(result)
+    }
+
+    /// Create a tandem repeat: `self`'s nucleotides repeated `times`. On error (if the result
+    /// would not fit) return [`Err`] with [`NucleotideError::Overflow`] with `OUT` (index of the
+    /// first nucleotide that doesn't fit).
+    pub fn repeat_self<const OUT: usize>(&self, times: usize) -> OurResult<RnaImpl<OUT>> {
+        let len = match self.len.checked_mul(times) {
+            Some(len) if len <= OUT => len,
+            _ => return Err(NucleotideError::Overflow(OUT)),
+        };
+        let mut result = RnaImpl::<OUT>::default();
+        // If `self` is empty, there is nothing to copy (regardless of `times`).
+        for i in 0..len / self.len.max(1) {
+            result.rna[i * self.len..(i + 1) * self.len].copy_from_slice(&self.rna[..self.len]);
+        }
+        result.len = len;
+        Ok(result)
+    }
+
     pub fn clone_max_size<const N: usize>(&self) -> RnaImpl<N> {
         assert!(self.len <= N, "Calling clone_max_size on an instance with len={}, but the target maximum size is insufficient: {}.", self.len, N);
         let mut rna = [u8::default(); N];
@@ -90,6 +413,120 @@ impl<const M: usize> RnaImpl<M> {
     }
 }
 
+/// Empty (zero length) RNA. All storage bytes are zero.
+///
+/// Unlike deriving [`Default`] (which is available for arrays only up to size `32`), this works for
+/// any `M`.
+impl<const M: usize> Default for RnaImpl<M> {
+    fn default() -> Self {
+        Self {
+            rna: [0u8; M],
+            len: 0,
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<const M: usize> RnaImpl<M> {
+    /// Substitute nucleotides at `num_mutations` distinct random positions, each with a random
+    /// different RNA nucleotide (A, C, G or U). If `num_mutations` is more than [`RnaImpl::len`], substitute all
+    /// nucleotides. The length doesn't change, and the result is valid RNA.
+    pub fn mutate<R: rand_core::RngCore>(&mut self, rng: &mut R, num_mutations: usize) {
+        const RNA_NUCLEOTIDES: [u8; 4] = *b"ACGU";
+        let mut remaining_mutations = num_mutations.min(self.len);
+        // Selection sampling (Knuth's Algorithm S): Select position `i` with probability
+        // remaining_mutations / remaining_positions. That picks exactly the required number of
+        // distinct positions, in one pass and without any extra storage.
+        for i in 0..self.len {
+            if remaining_mutations == 0 {
+                break;
+            }
+            let remaining_positions = self.len - i;
+            if (rng.next_u32() as usize) % remaining_positions < remaining_mutations {
+                let new = match RNA_NUCLEOTIDES.iter().position(|&b| b == self.rna[i]) {
+                    Some(current) => {
+                        // Shift by 1, 2 or 3, so we never pick the current nucleotide.
+                        let shift = 1 + rng.next_u32() as usize % (RNA_NUCLEOTIDES.len() - 1);
+                        (current + shift) % RNA_NUCLEOTIDES.len()
+                    }
+                    // N (any nucleotide, with feature `utils/iupac`) differs from all of them.
+                    None => rng.next_u32() as usize % RNA_NUCLEOTIDES.len(),
+                };
+                self.rna[i] = RNA_NUCLEOTIDES[new];
+                remaining_mutations -= 1;
+            }
+        }
+    }
+}
+
+/// Iterate over the used bytes (ASCII nucleotides). See [`RnaImpl::bytes`].
+impl<'a, const M: usize> IntoIterator for &'a RnaImpl<M> {
+    type Item = u8;
+    type IntoIter = Copied<slice::Iter<'a, u8>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.bytes().iter().copied()
+    }
+}
+
+/// Appends nucleotides written to it (for example, with `write!`) to the wrapped [`RnaImpl`]. Any
+/// invalid character, or overflow, fails with [`fmt::Error`], leaving the wrapped [`RnaImpl`]
+/// unchanged by that write. Create it with [`RnaImpl::writer`].
+pub struct RnaWriter<'a, const M: usize>(&'a mut RnaImpl<M>);
+
+impl<const M: usize> Write for RnaWriter<'_, M> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let len = self.0.len;
+        self.0.splice(len, len, s).map_err(|_| fmt::Error)
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const M: usize> RnaImpl<M> {
+    /// For each position, whether `self` and `other` have different nucleotides there (for
+    /// example, for visualization). On error (if their lengths differ) return [`Err`] with
+    /// [`NucleotideError::OutOfRange`] with the shorter length (index of the first position that
+    /// is not in both).
+    pub fn diff_mask<const N: usize>(
+        &self,
+        other: &RnaImpl<N>,
+    ) -> OurResult<heapless::Vec<bool, M>> {
+        if self.len != other.len {
+            return Err(NucleotideError::OutOfRange(self.len.min(other.len)));
+        }
+        let mask = self
+            .bytes()
+            .iter()
+            .zip(other.bytes())
+            .map(|(left, right)| left != right)
+            .collect();
+        Ok(mask)
+    }
+
+    /// Add all distinct (overlapping) k-mers (subsequences of `k` nucleotides) to `set`, in
+    /// addition to any k-mers already there. Const generic `K` is the capacity of the k-mers.
+    /// [`panic`] if `k` is zero, or if it's more than `K`.
+    ///
+    /// On error (if `set` is full) return [`Err`] with [`NucleotideError::Overflow`] with a 0-based
+    /// index of the first k-mer that could not be added. Any k-mers before it have been added.
+    pub fn distinct_kmers<const K: usize, const CAP: usize>(
+        &self,
+        k: usize,
+        set: &mut heapless::FnvIndexSet<heapless::String<K>, CAP>,
+    ) -> OurResult<()> {
+        assert!(
+            k > 0 && k <= K,
+            "distinct_kmers() needs k between 1 and {K}, but it is {k}."
+        );
+        for (i, kmer) in self.bytes().windows(k).enumerate() {
+            let kmer = str::from_utf8(kmer).expect("ASCII nucleotides");
+            let kmer = heapless::String::try_from(kmer).expect("k-mer within capacity");
+            set.insert(kmer).map_err(|_| NucleotideError::Overflow(i))?;
+        }
+        Ok(())
+    }
+}
+
 impl<const M: usize> Clone for RnaImpl<M> {
     fn clone(&self) -> Self {
         self.clone_max_size::<M>()
@@ -103,6 +540,22 @@ impl<'a, const L: usize, const R: usize> PartialEq<DnaImpl<'_, R>> for DnaImpl<'
 }
 impl<'a, const M: usize> Eq for DnaImpl<'a, M> {}
 
+impl<const M: usize> PartialEq<str> for DnaImpl<'_, M> {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+impl<const M: usize> PartialEq<DnaImpl<'_, M>> for str {
+    fn eq(&self, other: &DnaImpl<'_, M>) -> bool {
+        other == self
+    }
+}
+impl<const M: usize> PartialEq<DnaImpl<'_, M>> for &str {
+    fn eq(&self, other: &DnaImpl<'_, M>) -> bool {
+        other == *self
+    }
+}
+
 impl<const L: usize, const R: usize> PartialEq<RnaImpl<R>> for RnaImpl<L> {
     fn eq(&self, other: &RnaImpl<R>) -> bool {
         self.as_str() == other.as_str()
@@ -111,8 +564,531 @@ impl<const L: usize, const R: usize> PartialEq<RnaImpl<R>> for RnaImpl<L> {
 
 impl<const M: usize> Eq for RnaImpl<M> {}
 
+/// Lexicographic by nucleotides, so (like [`PartialEq`]) independent of `L` and `R`, and of any
+/// leftover storage.
+impl<const L: usize, const R: usize> PartialOrd<RnaImpl<R>> for RnaImpl<L> {
+    fn partial_cmp(&self, other: &RnaImpl<R>) -> Option<Ordering> {
+        Some(self.as_str().cmp(other.as_str()))
+    }
+}
+
+impl<const M: usize> Ord for RnaImpl<M> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const M: usize> PartialEq<str> for RnaImpl<M> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+impl<const M: usize> PartialEq<&str> for RnaImpl<M> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const M: usize> PartialEq<RnaImpl<M>> for str {
+    fn eq(&self, other: &RnaImpl<M>) -> bool {
+        other == self
+    }
+}
+impl<const M: usize> PartialEq<RnaImpl<M>> for &str {
+    fn eq(&self, other: &RnaImpl<M>) -> bool {
+        other == self
+    }
+}
+
 impl<const M: usize> Debug for RnaImpl<M> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
         write!(f, "Rna(\"{}\")", self.as_str())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Dna, DnaImpl, Rna, RnaImpl};
+    use utils::{DnaTrait, Nucleotide, NucleotideError, RnaTrait, RnaTraitMut};
+
+    #[test]
+    fn test_const_dna() {
+        let dna = const_dna!(4, "GCTA");
+        assert_eq!(dna, *"GCTA");
+        assert_eq!(dna.into_rna(), "CGAU");
+        assert_eq!(const_dna!(12, ""), *"");
+    }
+
+    #[test]
+    fn test_with_max() {
+        let dna = Dna::new("GCTA").unwrap();
+        let relabeled: DnaImpl<4> = dna.with_max();
+        assert_eq!(relabeled, *"GCTA");
+        assert_eq!(relabeled.into_rna().capacity(), 4);
+        assert_eq!(relabeled.into_rna(), "CGAU");
+    }
+
+    #[test]
+    fn test_try_with_max() {
+        let dna = Dna::new("GCTA").unwrap();
+        let relabeled = dna.try_with_max::<4>().unwrap();
+        assert_eq!(relabeled.into_rna(), "CGAU");
+        assert_eq!(dna.try_with_max::<20>().unwrap().into_rna(), "CGAU");
+        assert_eq!(dna.try_with_max::<3>(), Err(NucleotideError::Overflow(3)));
+    }
+
+    #[test]
+    fn test_dna_reverse_complement() {
+        let (bytes, len) = Dna::new("AAAACCCGGT")
+            .unwrap()
+            .reverse_complement()
+            .unwrap();
+        assert_eq!(&bytes[..len], b"ACCGGGTTTT");
+        assert_eq!(&bytes[len..], [0, 0]);
+
+        let dna = DnaImpl::<3>::new("AAAACCCGGT").unwrap();
+        assert_eq!(dna.reverse_complement(), Err(NucleotideError::Overflow(3)));
+    }
+
+    #[test]
+    fn test_dna_eq_str() {
+        assert_eq!(Dna::new("ACGT").unwrap(), *"ACGT");
+        assert_ne!(Dna::new("ACGT").unwrap(), *"ACG");
+        assert_eq!("ACGT", Dna::new("ACGT").unwrap());
+        assert_eq!(*"ACGT", Dna::new("ACGT").unwrap());
+    }
+
+    #[test]
+    fn test_rna_eq_str() {
+        let rna = Rna::new("CGAU").unwrap();
+        assert_eq!(rna, "CGAU");
+        assert_eq!("CGAU", rna);
+        assert_eq!(*"CGAU", rna);
+        assert_ne!("CGA", rna);
+    }
+
+    #[test]
+    fn test_default() {
+        let rna = RnaImpl::<64>::default();
+        assert_eq!(rna.len(), 0);
+        assert!(rna.is_empty());
+        assert_eq!(rna, Rna::new("").unwrap());
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "CGAU".parse::<RnaImpl<4>>().unwrap(),
+            Rna::new("CGAU").unwrap()
+        );
+        assert_eq!(
+            "CGAU".parse::<RnaImpl<8>>().unwrap(),
+            Rna::new("CGAU").unwrap()
+        );
+        assert_eq!(
+            "CGTU".parse::<RnaImpl<4>>(),
+            Err(NucleotideError::Invalid(2))
+        );
+        assert_eq!(
+            "CGAUA".parse::<RnaImpl<4>>(),
+            Err(NucleotideError::Overflow(4))
+        );
+    }
+
+    #[test]
+    fn test_try_from_array() {
+        let rna = RnaImpl::try_from(*b"CGAU").unwrap();
+        assert_eq!(rna.len(), 4);
+        assert_eq!(rna, Rna::new("CGAU").unwrap());
+
+        assert_eq!(
+            RnaImpl::try_from(*b"CGTU").unwrap_err(),
+            NucleotideError::Invalid(2)
+        );
+        assert_eq!(
+            RnaImpl::try_from([b'C', 0xC3]).unwrap_err(),
+            NucleotideError::Invalid(1)
+        );
+    }
+
+    #[test]
+    fn test_splice_longer() {
+        let mut rna = RnaImpl::<8>::new("CGAU").unwrap();
+        assert_eq!(rna.splice(1, 3, "UUUU"), Ok(()));
+        assert_eq!(rna, Rna::new("CUUUUU").unwrap());
+        assert_eq!(rna.len(), 6);
+    }
+
+    #[test]
+    fn test_splice_shorter_wipes() {
+        let mut rna = RnaImpl::<8>::new("CGAUCG").unwrap();
+        assert_eq!(rna.splice(1, 5, "A"), Ok(()));
+        assert_eq!(rna, Rna::new("CAG").unwrap());
+        assert_eq!(rna.rna[3..], [0u8; 5]);
+    }
+
+    #[test]
+    fn test_splice_errors() {
+        let mut rna = RnaImpl::<6>::new("CGAU").unwrap();
+        assert_eq!(rna.splice(1, 5, "A"), Err(NucleotideError::OutOfRange(5)));
+        assert_eq!(rna.splice(3, 2, "A"), Err(NucleotideError::OutOfRange(3)));
+        assert_eq!(rna.splice(1, 2, "AT"), Err(NucleotideError::Invalid(1)));
+        assert_eq!(rna.splice(1, 2, "AAAA"), Err(NucleotideError::Overflow(6)));
+        assert_eq!(rna, Rna::new("CGAU").unwrap());
+    }
+
+    #[test]
+    fn test_capacity() {
+        assert_eq!(Rna::new("CGAU").unwrap().capacity(), 12);
+        assert_eq!(RnaImpl::<20>::new("CGAU").unwrap().capacity(), 20);
+
+        // Usable in const contexts.
+        const RNA: RnaImpl<5> = RnaImpl {
+            rna: [0; 5],
+            len: 0,
+        };
+        const CAPACITY: usize = RNA.capacity();
+        assert_eq!([0u8; CAPACITY].len(), 5);
+    }
+
+    #[test]
+    fn test_as_ascii_array() {
+        let rna = Rna::new("CGAU").unwrap();
+        assert_eq!(rna.as_ascii_array::<4>(), Some(*b"CGAU"));
+        assert_eq!(rna.as_ascii_array::<3>(), None);
+        assert_eq!(rna.as_ascii_array::<5>(), None);
+        assert_eq!(Rna::new("").unwrap().as_ascii_array::<0>(), Some([]));
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut rnas = ["CGAU", "AUGC", "GGGG"].map(|rna| Rna::new(rna).unwrap());
+        rnas.sort();
+        assert_eq!(
+            rnas,
+            ["AUGC", "CGAU", "GGGG"].map(|rna| Rna::new(rna).unwrap())
+        );
+        assert!(rnas.binary_search(&Rna::new("CGAU").unwrap()).is_ok());
+
+        // Independent of `M`, and of any leftover storage.
+        assert!(Rna::new("CG").unwrap() < RnaImpl::<4>::new("CGA").unwrap());
+        let mut leftover = Rna::new("CG").unwrap();
+        leftover.rna[10] = b'U';
+        assert_eq!(
+            leftover.cmp(&Rna::new("CG").unwrap()),
+            core::cmp::Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_bytes() {
+        let mut rna = Rna::new("CGAUU").unwrap();
+        rna.truncate(4);
+        assert_eq!(rna.bytes(), b"CGAU");
+        assert_eq!(Rna::new("").unwrap().bytes(), b"");
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let rna = Rna::new("CGAU").unwrap();
+        let mut num_bytes = 0;
+        for b in &rna {
+            assert_eq!(b, b"CGAU"[num_bytes]);
+            num_bytes += 1;
+        }
+        assert_eq!(num_bytes, 4);
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn test_into_iter_collect() {
+        let rna = RnaImpl::<6>::new("CGAU").unwrap();
+        let bytes = (&rna).into_iter().collect::<heapless::Vec<u8, 6>>();
+        assert_eq!(bytes, b"CGAU");
+    }
+
+    #[test]
+    fn test_base() {
+        let rna = Rna::new("CGAU").unwrap();
+        assert_eq!(rna.base(0), Some(Nucleotide::C));
+        assert_eq!(rna.base(3), Some(Nucleotide::U));
+        assert_eq!(rna.base(4), None);
+        assert_eq!(rna.base(usize::MAX), None);
+    }
+
+    #[test]
+    fn test_iter_rev() {
+        let rna = Rna::new("CGAU").unwrap();
+        assert!(rna.iter_rev().eq(['U', 'A', 'G', 'C']));
+        assert_eq!(Rna::new("").unwrap().iter_rev().next(), None);
+    }
+
+    #[test]
+    fn test_count() {
+        let rna = Rna::new("CGAUA").unwrap();
+        assert_eq!(rna.count('A'), 2);
+        assert_eq!(rna.count('U'), 1);
+        assert_eq!(rna.count('T'), 0);
+    }
+
+    #[test]
+    fn test_base_histogram() {
+        assert_eq!(Rna::new("AACG").unwrap().base_histogram(), [2, 1, 1, 0]);
+        assert_eq!(Rna::new("").unwrap().base_histogram(), [0; 4]);
+    }
+
+    #[test]
+    fn test_count_differences() {
+        let rna = Rna::new("CGAU").unwrap();
+        assert_eq!(
+            rna.count_differences(&RnaImpl::<4>::new("CGAU").unwrap()),
+            Some(0)
+        );
+        assert_eq!(rna.count_differences(&Rna::new("CUAA").unwrap()), Some(2));
+        assert_eq!(rna.count_differences(&Rna::new("CGA").unwrap()), None);
+    }
+
+    #[test]
+    fn test_complement_in_place() {
+        let mut rna = Rna::new("ACGU").unwrap();
+        rna.complement_in_place();
+        assert_eq!(rna, Rna::new("UGCA").unwrap());
+        rna.complement_in_place();
+        assert_eq!(rna, Rna::new("ACGU").unwrap());
+    }
+
+    #[test]
+    fn test_reverse_complement_in_place() {
+        // "ACGU" is its own reverse complement.
+        let mut rna = Rna::new("ACGU").unwrap();
+        rna.reverse_complement_in_place();
+        assert_eq!(rna, Rna::new("ACGU").unwrap());
+        assert_eq!(rna.len(), 4);
+
+        let mut rna = Rna::new("AAGC").unwrap();
+        rna.reverse_complement_in_place();
+        assert_eq!(rna, Rna::new("GCUU").unwrap());
+        assert_eq!(rna.len(), 4);
+    }
+
+    #[test]
+    fn test_iupac_n() {
+        // Only with feature `utils/iupac`. Otherwise N is invalid.
+        let (Ok(dna), Ok(mut rna)) = (Dna::new("ACGN"), Rna::new("ANGU")) else {
+            return;
+        };
+        let (bytes, len) = dna.reverse_complement().unwrap();
+        assert_eq!(&bytes[..len], b"NCGT");
+        assert_eq!(rna.base(1).map(char::from), Some('N'));
+        assert_eq!(rna.base_histogram(), [1, 0, 1, 1]);
+        rna.reverse_complement_in_place();
+        assert_eq!(rna, *"ACNU");
+    }
+
+    #[test]
+    fn test_push_codon() {
+        let mut rna = RnaImpl::<8>::default();
+        rna.push_codon(['A', 'U', 'G']).unwrap();
+        rna.push_codon(['U', 'U', 'U']).unwrap();
+        assert_eq!(rna, Rna::new("AUGUUU").unwrap());
+
+        assert_eq!(
+            rna.push_codon(['U', 'T', 'A']),
+            Err(NucleotideError::Invalid(1))
+        );
+        assert_eq!(
+            rna.push_codon(['U', 'A', 'A']),
+            Err(NucleotideError::Overflow(8))
+        );
+        assert_eq!(rna, Rna::new("AUGUUU").unwrap());
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut rna = RnaImpl::<6>::new("CGA").unwrap();
+        rna.insert(0, 'U').unwrap();
+        assert_eq!(rna, Rna::new("UCGA").unwrap());
+        rna.insert(2, 'A').unwrap();
+        assert_eq!(rna, Rna::new("UCAGA").unwrap());
+        rna.insert(5, 'G').unwrap();
+        assert_eq!(rna, Rna::new("UCAGAG").unwrap());
+
+        assert_eq!(rna.insert(0, 'C'), Err(NucleotideError::Overflow(6)));
+        assert_eq!(rna.insert(7, 'C'), Err(NucleotideError::OutOfRange(7)));
+        assert_eq!(rna, Rna::new("UCAGAG").unwrap());
+
+        let mut rna = RnaImpl::<6>::new("CG").unwrap();
+        assert_eq!(rna.insert(1, 'T'), Err(NucleotideError::Invalid(0)));
+        assert_eq!(rna, Rna::new("CG").unwrap());
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut rna = RnaImpl::<6>::new("UCAGA").unwrap();
+        assert_eq!(rna.remove(0), Ok('U'));
+        assert_eq!(rna, Rna::new("CAGA").unwrap());
+        assert_eq!(rna.remove(1), Ok('A'));
+        assert_eq!(rna, Rna::new("CGA").unwrap());
+        assert_eq!(rna.remove(2), Ok('A'));
+        assert_eq!(rna, Rna::new("CG").unwrap());
+        // The freed slots are wiped.
+        assert_eq!(rna.rna, *b"CG\0\0\0\0");
+
+        assert_eq!(rna.remove(2), Err(NucleotideError::OutOfRange(2)));
+        assert_eq!(rna, Rna::new("CG").unwrap());
+    }
+
+    #[test]
+    fn test_writer() {
+        use core::fmt::Write;
+        let mut rna = RnaImpl::<6>::default();
+        write!(rna.writer(), "CG").unwrap();
+        let tail = "AU";
+        write!(rna.writer(), "{tail}").unwrap();
+        assert_eq!(rna, "CGAU");
+
+        assert!(write!(rna.writer(), "AT").is_err());
+        assert!(write!(rna.writer(), "AAA").is_err());
+        assert_eq!(rna, "CGAU");
+    }
+
+    #[test]
+    fn test_try_concat() {
+        let left = Rna::new("CGA").unwrap();
+        let right = RnaImpl::<4>::new("UAC").unwrap();
+        assert_eq!(
+            left.try_concat::<4, 6>(&right),
+            Ok(RnaImpl::<6>::new("CGAUAC").unwrap())
+        );
+        assert_eq!(
+            left.try_concat::<4, 5>(&right),
+            Err(NucleotideError::Overflow(6))
+        );
+        assert_eq!(
+            left.try_concat::<12, 3>(&Rna::new("").unwrap()),
+            Ok(RnaImpl::<3>::new("CGA").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_repeat_self() {
+        let rna = Rna::new("AU").unwrap();
+        assert_eq!(rna.repeat_self::<6>(3), Ok(RnaImpl::new("AUAUAU").unwrap()));
+        assert_eq!(rna.repeat_self::<8>(0), Ok(RnaImpl::new("").unwrap()));
+        assert_eq!(rna.repeat_self::<5>(3), Err(NucleotideError::Overflow(5)));
+        assert_eq!(
+            rna.repeat_self::<5>(usize::MAX),
+            Err(NucleotideError::Overflow(5))
+        );
+        assert_eq!(
+            Rna::new("").unwrap().repeat_self::<4>(usize::MAX),
+            Ok(RnaImpl::new("").unwrap())
+        );
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn test_diff_mask() {
+        let rna = Rna::new("CGAU").unwrap();
+        let mask = rna.diff_mask(&Rna::new("CGUU").unwrap()).unwrap();
+        assert_eq!(mask, [false, false, true, false]);
+        assert_eq!(
+            rna.diff_mask(&Rna::new("CGA").unwrap()),
+            Err(NucleotideError::OutOfRange(3))
+        );
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn test_distinct_kmers() {
+        let rna = Rna::new("AAAA").unwrap();
+        let mut set = heapless::FnvIndexSet::<heapless::String<2>, 4>::new();
+        rna.distinct_kmers(2, &mut set).unwrap();
+        assert_eq!(set.len(), 1);
+        assert!(set.contains(&heapless::String::try_from("AA").unwrap()));
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn test_distinct_kmers_full() {
+        let rna = Rna::new("ACGUA").unwrap();
+        let mut set = heapless::FnvIndexSet::<heapless::String<2>, 2>::new();
+        assert_eq!(
+            rna.distinct_kmers(2, &mut set),
+            Err(NucleotideError::Overflow(2))
+        );
+        assert_eq!(set.len(), 2);
+    }
+
+    /// Deterministic (xorshift) generator, so that tests are repeatable.
+    #[cfg(feature = "rand")]
+    struct TestRng(u64);
+
+    #[cfg(feature = "rand")]
+    impl rand_core::RngCore for TestRng {
+        fn next_u32(&mut self) -> u32 {
+            (self.next_u64() >> 32) as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            rand_core::impls::fill_bytes_via_next(self, dst)
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_mutate() {
+        let original = Rna::new("ACGUACGUACGU").unwrap();
+
+        let mut rng = TestRng(0x2545_f491_4f6c_dd1d);
+        let mut mutated = original.clone();
+        mutated.mutate(&mut rng, 3);
+
+        assert_eq!(mutated.len(), original.len());
+        assert!(utils::checks::check_rna_str(mutated.as_str()).is_ok());
+        let num_changed = original
+            .as_str()
+            .chars()
+            .zip(mutated.as_str().chars())
+            .filter(|(l, r)| l != r)
+            .count();
+        assert_eq!(num_changed, 3);
+
+        // Same seed, same result.
+        let mut rng = TestRng(0x2545_f491_4f6c_dd1d);
+        let mut mutated_again = original.clone();
+        mutated_again.mutate(&mut rng, 3);
+        assert_eq!(mutated, mutated_again);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_mutate_more_than_len() {
+        let original = Rna::new("ACGU").unwrap();
+        let mut mutated = original.clone();
+        mutated.mutate(&mut TestRng(7), 10);
+        assert!(mutated
+            .as_str()
+            .chars()
+            .zip(original.as_str().chars())
+            .all(|(l, r)| l != r));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_mutate_iupac_n() {
+        // Only with feature `utils/iupac`. Otherwise N is invalid.
+        let Ok(mut rna) = Rna::new("NNNN") else {
+            return;
+        };
+        rna.mutate(&mut TestRng(7), 4);
+        assert!(rna.as_str().chars().all(|c| "ACGU".contains(c)));
+    }
+}
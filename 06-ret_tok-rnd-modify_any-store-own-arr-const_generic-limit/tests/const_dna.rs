@@ -0,0 +1,5 @@
+#[test]
+fn const_dna_too_long() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/const_dna_too_long.rs");
+}
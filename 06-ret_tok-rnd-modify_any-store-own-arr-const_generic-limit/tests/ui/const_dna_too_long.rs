@@ -0,0 +1,5 @@
+use ret_tok_rnd_modify_any_store_own_arr_const_generic_limit::const_dna;
+
+fn main() {
+    let _dna = const_dna!(3, "GCTA");
+}
@@ -34,11 +34,12 @@ impl<'a> Dna<'a> {
         Ok(Self(dna))
     }
 
-    pub fn into_rna<'s>(&self, storage: &'s mut [u8]) -> Rna
+    pub fn into_rna<'s>(&self, storage: &'s mut [u8]) -> Rna<'_>
     where
         's: 'a,
     {
-        Rna::new_from_iter_and_storage(self.0.chars().map(utils::dna_to_rna), storage).expect("RNA")
+        let len = utils::transcribe_slice(self.0.as_bytes(), storage).expect("RNA");
+        Rna::MutableNucleotides { rna: storage, len }
     }
 }
 
@@ -48,17 +49,24 @@ impl<'a> Rna<'a> {
         Ok(Self::GivenNucleotides(rna))
     }
 
-    fn new_from_iter_and_storage<'s>(
-        rna_iter: impl Iterator<Item = char>,
-        storage: &'s mut [u8],
-    ) -> OurResult<Self>
-    where
-        's: 'a,
-    {
-        let len = utils::char_iter_to_bytes(storage, rna_iter);
-        let result = Self::MutableNucleotides { rna: storage, len };
-        checks::check_rna_str(result.as_str())?;
-        Ok(result)
+    /// Keep only the first `new_len` nucleotides. If `new_len` is not less than the current
+    /// length, this has no effect. For [`Rna::MutableNucleotides`] this wipes (zeroes) the part
+    /// of the storage that is no longer used. ([`Rna::GivenNucleotides`] doesn't own its data, so
+    /// it's only re-sliced.)
+    pub fn truncate(&mut self, new_len: usize) {
+        match self {
+            Self::GivenNucleotides(rna) => {
+                if new_len < rna.len() {
+                    *rna = &rna[..new_len];
+                }
+            }
+            Self::MutableNucleotides { rna, len } => {
+                if new_len < *len {
+                    rna[new_len..*len].fill(0);
+                    *len = new_len;
+                }
+            }
+        }
     }
 
     fn as_str(&self) -> &str {
@@ -127,4 +135,23 @@ pub mod test {
         assert_eq!(rna_ref, dna_transformed_ref);
         assert_eq!(dna_transformed_ref, rna_ref);
     }
+
+    #[test]
+    fn test_truncate() {
+        let mut rna = Rna::new("CGAU").unwrap();
+        rna.truncate(2);
+        assert_eq!(rna, Rna::new("CG").unwrap());
+        rna.truncate(3);
+        assert_eq!(rna, Rna::new("CG").unwrap());
+
+        let dna = Dna::new("GCTA").unwrap();
+        let mut storage = [0u8; 5];
+        let mut rna = dna.into_rna(&mut storage);
+        rna.truncate(1);
+        assert_eq!(rna, Rna::new("C").unwrap());
+        match rna {
+            Rna::MutableNucleotides { rna, .. } => assert_eq!(rna, [b'C', 0, 0, 0, 0]),
+            Rna::GivenNucleotides(_) => unreachable!(),
+        }
+    }
 }
@@ -37,7 +37,7 @@ impl<'a> Dna<'a> {
 
     /// TODO doc.
     /// Return the UTF-8 length.
-    pub fn prepare_storage_from_dna<'s>(&self, storage: &'s mut [u8]) -> usize {
+    pub fn prepare_storage_from_dna(&self, storage: &mut [u8]) -> usize {
         utils::char_iter_to_bytes(storage, self.0.chars().map(utils::dna_to_rna))
     }
 }
@@ -83,7 +83,7 @@ impl<'l, 'r> PartialEq<Rna<'r>> for &Rna<'l> {
 #[cfg(test)]
 pub mod test {
     extern crate alloc;
-    use super::{into_rna, Dna, Rna};
+    use super::{Dna, Rna};
 
     /// Testing that equality is defined for references - because we can't share instances of this
     /// type in any other way.
@@ -1,5 +1,4 @@
 #![no_std]
-#![feature(generic_associated_types)]
 
 use ret_tok_seq_non_modify_trans_shr_box_dyn_map as dna;
 
@@ -27,6 +27,10 @@ impl<'a> DnaTrait<'a, Rna<'a>> for Dna<'a> {
         Ok(Self(dna))
     }
 
+    fn as_str(&self) -> &str {
+        self.0
+    }
+
     /// Create a [DNA-based variant of `Rna`](Rna::GivenNucleotides) instance, based on `self`. No
     /// transformation/iteration is done yet - see [`Rna::DnaBased`].
     fn into_rna(&self) -> Rna<'a> {
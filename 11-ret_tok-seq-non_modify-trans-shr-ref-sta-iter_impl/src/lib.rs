@@ -1,8 +1,10 @@
 //! no_std heapless (bare metal/embedded-friendly)
 #![no_std]
 
-use core::fmt::{self, Debug, Formatter};
-use utils::{checks, DnaTrait, OurResult, RnaTrait};
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use ret_tok_rnd_modify_any_store_own_arr_const_generic_limit::RnaImpl;
+use utils::{checks, DnaTrait, NucleotideError, OurResult, RnaTrait};
 
 /// DNA (DNA nucleotide sequence).  
 /// Implementing [`Eq`] is not necessary, but valid.
@@ -28,6 +30,10 @@ impl<'a> DnaTrait<'a, Rna<'a>> for Dna<'a> {
         Ok(Self(dna))
     }
 
+    fn as_str(&self) -> &str {
+        self.0
+    }
+
     /// Create a [DNA-based variant of `Rna`](Rna::GivenNucleotides) instance, based on `self`. No
     /// transformation/iteration is done yet - see [`Rna::DnaBased`].
     fn into_rna(&self) -> Rna<'a> {
@@ -37,6 +43,85 @@ impl<'a> DnaTrait<'a, Rna<'a>> for Dna<'a> {
     }
 }
 
+impl<'a> Dna<'a> {
+    /// Like [`Dna::new`], but from bytes (borrowed, not copied). On error return [`Err`] with
+    /// [`NucleotideError::Invalid`] with a 0-based index of the first incorrect byte, including
+    /// any non-ASCII (or non-UTF-8) byte.
+    pub fn from_bytes(bytes: &'a [u8]) -> OurResult<Dna<'a>> {
+        match core::str::from_utf8(bytes) {
+            Ok(dna) => Self::new(dna),
+            Err(e) => {
+                let valid = e.valid_up_to();
+                // Any error within the valid UTF-8 prefix comes first.
+                checks::check_dna(core::str::from_utf8(&bytes[..valid]).expect("valid UTF-8"))?;
+                // The prefix consists of ASCII nucleotides, so `valid` is also a char index.
+                Err(NucleotideError::Invalid(valid))
+            }
+        }
+    }
+
+    /// Split into two [`Dna`] instances: nucleotides before `mid`, and from `mid` on. This doesn't
+    /// copy any data - both results borrow from the same `&str` as `self`. On error (if `mid` is
+    /// past the end) return [`Err`] with [`NucleotideError::OutOfRange`] with `mid`.
+    pub fn split_at(&self, mid: usize) -> OurResult<(Dna<'a>, Dna<'a>)> {
+        if mid > self.0.len() {
+            return Err(NucleotideError::OutOfRange(mid));
+        }
+        // DNA nucleotides are ASCII, so any index up to `len()` is a char boundary.
+        let (left, right) = self.0.split_at(mid);
+        Ok((Dna(left), Dna(right)))
+    }
+
+    /// Nucleotides from offset `n` on (for example, for reading frame `n`). This doesn't copy any
+    /// data. On error (if `n` is past the end) return [`Err`] with [`NucleotideError::OutOfRange`]
+    /// with `n`.
+    pub fn frame(&self, n: usize) -> OurResult<Dna<'a>> {
+        let (_, frame) = self.split_at(n)?;
+        Ok(frame)
+    }
+
+    /// Iterate over consecutive sub-[`Dna`]s of `n` nucleotides each (the last one may be
+    /// shorter). This doesn't copy any data. [`panic`] if `n` is zero.
+    pub fn chunks(&self, n: usize) -> impl Iterator<Item = Dna<'a>> + '_ {
+        assert!(n > 0, "chunks() needs a non-zero chunk size.");
+        let dna = self.0;
+        // DNA nucleotides are ASCII, so any index up to `len()` is a char boundary.
+        (0..dna.len())
+            .step_by(n)
+            .map(move |start| Dna(&dna[start..dna.len().min(start + n)]))
+    }
+
+    /// Ratio of G and C nucleotides, between `0.0` and `1.0`. Return `0.0` if empty. This iterates
+    /// once over the borrowed nucleotides, without allocating.
+    pub fn gc_content(&self) -> f32 {
+        let (gc, total) = self.0.chars().fold((0usize, 0usize), |(gc, total), c| {
+            (gc + usize::from(c == 'G' || c == 'C'), total + 1)
+        });
+        if total == 0 {
+            0.0
+        } else {
+            gc as f32 / total as f32
+        }
+    }
+
+    /// Transcribe into an owned [`RnaImpl<M>`], which (unlike [`Rna`]) doesn't borrow from `self`,
+    /// so it can outlive the DNA. On error (if longer than `M`) return [`Err`] with
+    /// [`NucleotideError::Overflow`].
+    pub fn to_owned_rna_heapless<const M: usize>(&self) -> OurResult<RnaImpl<M>> {
+        RnaImpl::new_from_iter(self.into_rna().iter())
+    }
+
+    /// Number of leading nucleotides shared by `self` and `other`. This compares bytes in place,
+    /// without copying.
+    pub fn common_prefix_len(&self, other: &Dna<'a>) -> usize {
+        self.0
+            .bytes()
+            .zip(other.0.bytes())
+            .take_while(|(left, right)| left == right)
+            .count()
+    }
+}
+
 impl<'a> RnaTrait<'a> for Rna<'a> {
     /// Create a new [`Rna`] instance with given RNA nucleotides -[`Rna::GivenNucleotides`] variant.
     /// If `rna` is valid, return  
@@ -65,12 +150,13 @@ impl<'a> Rna<'a> {
     /// Create an [`Iterator`] over `self`'s RNA nucleotides (chars). For  
     /// [RNA-based variant](Rna::GivenNucleotides) this iterates over the given nucleotides. For  
     /// [DNA-based variant](Rna::DnaBased) this translates the DNA nucleotides to RNA ones on the
-    /// fly (without storing them anywhere).
+    /// fly (without storing them anywhere). It's double-ended, so it can be reversed with
+    /// [`Iterator::rev`] (translating DNA nucleotides from the back, too).
     ///
     /// This return type can't be declared as `impl Iterator<Item = char> + 'a`, but it has to use
     /// `_` which indicates _lifetime elision_. Thanks to
     /// https://robinmoussu.gitlab.io/blog/post/2021-03-25_rust_iterators_tips_and_tricks.
-    fn iter(&self) -> impl Iterator<Item = char> + '_ {
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = char> + '_ {
         self.stored_nucleotides().chars().map(|c| {
             if self.is_dna_based() {
                 utils::dna_to_rna(c)
@@ -88,6 +174,38 @@ impl<'a> PartialEq for Rna<'a> {
 }
 impl<'a> Eq for Rna<'a> {}
 
+/// Consistent with [`PartialEq`]: it hashes the (possibly translated) RNA nucleotides, so a
+/// [`Rna::DnaBased`] and an equal [`Rna::GivenNucleotides`] have the same hash.
+impl Hash for Rna<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.iter().for_each(|c| c.hash(state));
+        // Like `str`'s `Hash`, mark the end, so that concatenations hash differently.
+        state.write_u8(0xff);
+    }
+}
+
+impl PartialEq<str> for Rna<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.iter().eq(other.chars())
+    }
+}
+impl PartialEq<&str> for Rna<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.iter().eq(other.chars())
+    }
+}
+
+impl PartialEq<Rna<'_>> for str {
+    fn eq(&self, other: &Rna<'_>) -> bool {
+        other == self
+    }
+}
+impl PartialEq<Rna<'_>> for &str {
+    fn eq(&self, other: &Rna<'_>) -> bool {
+        other == self
+    }
+}
+
 impl<'a> Debug for Rna<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
         write!(f, "Rna(\"")?;
@@ -95,3 +213,183 @@ impl<'a> Debug for Rna<'a> {
         write!(f, "\")")
     }
 }
+
+/// Only the (possibly translated) RNA nucleotides, without quotes or the type name (for example,
+/// for logging). This doesn't allocate.
+impl<'a> Display for Rna<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        self.iter().try_for_each(|c| write!(f, "{c}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::{Dna, Rna, RnaImpl};
+    use alloc::format;
+    use core::hash::{Hash, Hasher};
+    use utils::{DnaTrait, NucleotideError, RnaTrait};
+
+    /// FNV-1a, as a minimal no_std [`Hasher`] for tests.
+    struct FnvHasher(u64);
+
+    impl Hasher for FnvHasher {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for &b in bytes {
+                self.0 = (self.0 ^ u64::from(b)).wrapping_mul(0x100_0000_01b3);
+            }
+        }
+    }
+
+    fn hash_of(rna: &Rna) -> u64 {
+        let mut hasher = FnvHasher(0xcbf2_9ce4_8422_2325);
+        rna.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        assert_eq!(Dna::from_bytes(b"GCTA"), Ok(Dna::new("GCTA").unwrap()));
+        assert_eq!(Dna::from_bytes(b""), Ok(Dna::new("").unwrap()));
+        assert_eq!(Dna::from_bytes(b"GCUA"), Err(NucleotideError::Invalid(2)));
+        // Non-ASCII: "é" (valid UTF-8), and a lone 0xFF (invalid UTF-8).
+        assert_eq!(
+            Dna::from_bytes("GCé".as_bytes()),
+            Err(NucleotideError::Invalid(2))
+        );
+        assert_eq!(
+            Dna::from_bytes(b"GC\xFFA"),
+            Err(NucleotideError::Invalid(2))
+        );
+        assert_eq!(
+            Dna::from_bytes(b"GX\xFFA"),
+            Err(NucleotideError::Invalid(1))
+        );
+    }
+
+    #[test]
+    fn test_to_owned_rna_heapless() {
+        let rna: RnaImpl<8> = {
+            // Local (non-'static) storage, so that the DNA can't outlive this block.
+            let dna_bytes = *b"GCTA";
+            let dna = Dna::new(core::str::from_utf8(&dna_bytes).unwrap()).unwrap();
+            dna.to_owned_rna_heapless().unwrap()
+        };
+        assert_eq!(rna, "CGAU");
+
+        let dna = Dna::new("GCTA").unwrap();
+        assert_eq!(
+            dna.to_owned_rna_heapless::<3>().err(),
+            Some(NucleotideError::Overflow(3))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let dna = Dna::new("GCTA").unwrap();
+        assert_eq!(format!("{}", dna.into_rna()), "CGAU");
+        assert_eq!(format!("{}", Rna::new("CGAU").unwrap()), "CGAU");
+        assert_eq!(format!("{}", Rna::new("").unwrap()), "");
+    }
+
+    #[test]
+    fn test_hash_matches_eq() {
+        let dna_based = Dna::new("GCTA").unwrap().into_rna();
+        let given = Rna::new("CGAU").unwrap();
+        assert_eq!(dna_based, given);
+        assert_eq!(hash_of(&dna_based), hash_of(&given));
+
+        let other = Rna::new("CGAA").unwrap();
+        assert_ne!(hash_of(&given), hash_of(&other));
+    }
+
+    #[test]
+    fn test_split_at() {
+        let dna = Dna::new("ACGT").unwrap();
+        assert_eq!(
+            dna.split_at(2),
+            Ok((Dna::new("AC").unwrap(), Dna::new("GT").unwrap()))
+        );
+        assert_eq!(
+            dna.split_at(0),
+            Ok((Dna::new("").unwrap(), Dna::new("ACGT").unwrap()))
+        );
+        assert_eq!(
+            dna.split_at(4),
+            Ok((Dna::new("ACGT").unwrap(), Dna::new("").unwrap()))
+        );
+        assert_eq!(dna.split_at(5), Err(NucleotideError::OutOfRange(5)));
+    }
+
+    #[test]
+    fn test_eq_str() {
+        let rna = Dna::new("GCTA").unwrap().into_rna();
+        assert_eq!(rna, "CGAU");
+        assert_eq!("CGAU", rna);
+        assert_eq!(*"CGAU", rna);
+        assert_ne!("CGA", rna);
+        assert_eq!("CGAU", Rna::new("CGAU").unwrap());
+    }
+
+    #[test]
+    fn test_iter_rev() {
+        let rna = Dna::new("GCTAA").unwrap().into_rna();
+        assert!(rna.iter().rev().eq("CGAUU".chars().rev()));
+        let rna = Rna::new("CGAUU").unwrap();
+        assert!(rna.iter().rev().eq("UUAGC".chars()));
+    }
+
+    #[test]
+    fn test_frame() {
+        let dna = Dna::new("ACGTAC").unwrap();
+        assert_eq!(dna.frame(0), Ok(dna));
+        assert_eq!(dna.frame(1), Ok(Dna::new("CGTAC").unwrap()));
+        assert_eq!(dna.frame(2), Ok(Dna::new("GTAC").unwrap()));
+        assert_eq!(dna.frame(6), Ok(Dna::new("").unwrap()));
+        assert_eq!(dna.frame(7), Err(NucleotideError::OutOfRange(7)));
+    }
+
+    #[test]
+    fn test_chunks() {
+        let dna = Dna::new("ACGTAC").unwrap();
+        let mut chunks = dna.chunks(2);
+        assert_eq!(chunks.next(), Some(Dna::new("AC").unwrap()));
+        assert_eq!(chunks.next(), Some(Dna::new("GT").unwrap()));
+        assert_eq!(chunks.next(), Some(Dna::new("AC").unwrap()));
+        assert_eq!(chunks.next(), None);
+
+        let mut chunks = dna.chunks(4);
+        assert_eq!(chunks.next(), Some(Dna::new("ACGT").unwrap()));
+        assert_eq!(chunks.next(), Some(Dna::new("AC").unwrap()));
+        assert_eq!(chunks.next(), None);
+
+        assert_eq!(Dna::new("").unwrap().chunks(3).next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_chunks_zero() {
+        let _ = Dna::new("ACGT").unwrap().chunks(0);
+    }
+
+    #[test]
+    fn test_gc_content() {
+        assert_eq!(Dna::new("GCGC").unwrap().gc_content(), 1.0);
+        assert_eq!(Dna::new("ATAT").unwrap().gc_content(), 0.0);
+        assert_eq!(Dna::new("GATC").unwrap().gc_content(), 0.5);
+        assert_eq!(Dna::new("").unwrap().gc_content(), 0.0);
+    }
+
+    #[test]
+    fn test_common_prefix_len() {
+        let dna = Dna::new("ACGT").unwrap();
+        assert_eq!(dna.common_prefix_len(&Dna::new("ACGT").unwrap()), 4);
+        assert_eq!(dna.common_prefix_len(&Dna::new("ACTTA").unwrap()), 2);
+        assert_eq!(dna.common_prefix_len(&Dna::new("TACG").unwrap()), 0);
+        assert_eq!(dna.common_prefix_len(&Dna::new("").unwrap()), 0);
+    }
+}
@@ -1,9 +1,9 @@
 #![no_std]
-#![feature(generic_associated_types)]
 
 use ret_tok_seq_non_modify_trans_shr_ref_sta_iter_impl as dna;
 
 use test_harness::api_tests_read_only::Tests;
+use utils::{DnaTrait, RnaTrait};
 
 struct T {}
 impl Tests for T {
@@ -15,3 +15,12 @@ impl Tests for T {
 fn all_tests() {
     T::all_tests();
 }
+
+#[test]
+fn test_iter_rev() {
+    let dna_based = dna::Dna::new("GCTA").unwrap().into_rna();
+    assert!(dna_based.iter().rev().eq("UAGC".chars()));
+
+    let given = dna::Rna::new("CGAU").unwrap();
+    assert!(given.iter().rev().eq("UAGC".chars()));
+}
@@ -19,6 +19,10 @@ impl<'a> DnaTrait<'a, Rna<'a>> for Dna<'a> {
         Ok(Self(dna))
     }
 
+    fn as_str(&self) -> &str {
+        self.0
+    }
+
     fn into_rna(&self) -> Rna<'a> {
         match self {
             Dna(dna) => Rna::DnaBased(dna),
@@ -43,11 +47,9 @@ impl<'a> PartialEq for Rna<'a> {
         // and `other`.
         let (mut self_rna_chars, mut self_dna_chars_mapped);
         let (mut other_rna_chars, mut other_dna_chars_mapped);
-        let self_chars: &mut dyn Iterator<Item = char>;
-        let other_chars: &mut dyn Iterator<Item = char>;
 
         // @TODO factor to a method
-        self_chars = match self {
+        let self_chars: &mut dyn Iterator<Item = char> = match self {
             Self::GivenNucleotides(rna) => {
                 self_rna_chars = rna.chars();
                 &mut self_rna_chars
@@ -57,7 +59,7 @@ impl<'a> PartialEq for Rna<'a> {
                 &mut self_dna_chars_mapped
             }
         };
-        other_chars = match other {
+        let other_chars: &mut dyn Iterator<Item = char> = match other {
             Self::GivenNucleotides(rna) => {
                 other_rna_chars = rna.chars();
                 &mut other_rna_chars
@@ -1,5 +1,4 @@
 #![no_std]
-#![feature(generic_associated_types)]
 
 use coupled_seq_non_modify_trans_shr_ref_sta_eq_iters_matrix as dna;
 
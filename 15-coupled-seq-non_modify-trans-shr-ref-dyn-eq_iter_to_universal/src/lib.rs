@@ -22,6 +22,10 @@ impl<'a> DnaTrait<'a, Rna<'a>> for Dna<'a> {
         Ok(Self(dna))
     }
 
+    fn as_str(&self) -> &str {
+        self.0
+    }
+
     fn into_rna(&self) -> Rna<'a> {
         match self {
             Dna(dna) => Rna::DnaBased(dna),
@@ -41,6 +45,18 @@ impl<'a> RnaTrait<'a> for Rna<'a> {
 }
 
 impl<'a> Rna<'a> {
+    /// Iterate over `self`'s RNA nucleotides. For [RNA-based variant](Rna::GivenNucleotides) this
+    /// yields the given nucleotides. For [DNA-based variant](Rna::DnaBased) this translates the DNA
+    /// nucleotides to RNA ones on the fly (without storing them anywhere).
+    pub fn nucleotides(&self) -> impl Iterator<Item = char> + '_ {
+        // Both variants share one iterator type, so we choose the mapping function instead.
+        let (stored, map): (&str, fn(char) -> char) = match *self {
+            Rna::GivenNucleotides(rna) => (rna, |c| c),
+            Rna::DnaBased(dna) => (dna, utils::dna_to_rna),
+        };
+        stored.chars().map(map)
+    }
+
     /// Get an [`Iterator`] over `self`'s RNA nucleotides (chars), and call `closure` with that
     /// (`self`'s) iterator and `other_rna_chars`. For  
     /// [RNA-based variant](Rna::GivenNucleotides) this iterates over the given nucleotides. For  
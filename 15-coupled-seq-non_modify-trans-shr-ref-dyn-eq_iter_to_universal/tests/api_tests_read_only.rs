@@ -1,9 +1,9 @@
 #![no_std]
-#![feature(generic_associated_types)]
 
 use coupled_seq_non_modify_trans_shr_ref_dyn_eq_iter_to_universal as dna;
 
 use test_harness::api_tests_read_only::Tests;
+use utils::{DnaTrait, RnaTrait};
 
 struct T {}
 impl Tests for T {
@@ -15,3 +15,12 @@ impl Tests for T {
 fn all_tests() {
     T::all_tests();
 }
+
+#[test]
+fn test_nucleotides() {
+    let dna_based = dna::Dna::new("GCTA").unwrap().into_rna();
+    assert!(dna_based.nucleotides().eq("CGAU".chars()));
+
+    let given = dna::Rna::new("CGAU").unwrap();
+    assert!(given.nucleotides().eq("CGAU".chars()));
+}
@@ -18,6 +18,10 @@ impl<'a> DnaTrait<'a, Rna<'a>> for Dna<'a> {
         Ok(Self(dna))
     }
 
+    fn as_str(&self) -> &str {
+        self.0
+    }
+
     fn into_rna(&self) -> Rna<'a> {
         match self {
             Dna(dna) => Rna::DnaBased(dna),
@@ -35,7 +39,7 @@ impl<'a> RnaTrait<'a> for Rna<'a> {
 impl<'a> Rna<'a> {
     /// - Generic param P - type of the parameter to pass to the closure.
     /// - Generic param P - result type from the closure, to be returned from this
-    /// `with_chars_universal`.
+    ///   `with_chars_universal`.
     /// - Generic param C - closure to call, with a (dynamic) iterator over chars from `self`, and
     ///   with the given `param`.
     /// - Return: Result of the call to `closure`.
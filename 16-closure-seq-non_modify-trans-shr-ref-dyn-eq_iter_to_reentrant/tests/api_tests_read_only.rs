@@ -1,5 +1,4 @@
 #![no_std]
-#![feature(generic_associated_types)]
 
 use closure_seq_non_modify_trans_shr_ref_dyn_eq_iter_to_reentrant as dna;
 